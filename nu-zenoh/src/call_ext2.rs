@@ -16,7 +16,7 @@ use std::time::Duration;
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, EngineState, Stack},
-    LabeledError, Value,
+    LabeledError, Spanned, Value,
 };
 use zenoh::{
     bytes::Encoding,
@@ -26,7 +26,10 @@ use zenoh::{
     time::Timestamp,
 };
 
-use crate::State;
+use crate::{
+    flow_control::{FlowControl, Overflow},
+    State,
+};
 
 pub(crate) trait CallExt2 {
     fn allowed_origin(
@@ -112,6 +115,24 @@ pub(crate) trait CallExt2 {
         engine_state: &EngineState,
         stack: &mut Stack,
     ) -> Result<Option<Duration>, LabeledError>;
+
+    fn interval(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+    ) -> Result<Option<Duration>, LabeledError>;
+
+    fn size(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+    ) -> Result<Option<usize>, LabeledError>;
+
+    fn flow_control(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+    ) -> Result<FlowControl, LabeledError>;
 }
 
 impl CallExt2 for Call<'_> {
@@ -120,8 +141,8 @@ impl CallExt2 for Call<'_> {
         engine_state: &EngineState,
         stack: &mut Stack,
     ) -> Result<Option<Locality>, LabeledError> {
-        self.get_flag::<String>(engine_state, stack, "allowed-origin")?
-            .map(|o| parse_locality(&o, self.head))
+        self.get_flag::<Spanned<String>>(engine_state, stack, "allowed-origin")?
+            .map(|o| parse_locality(&o.item, o.span))
             .transpose()
     }
 
@@ -130,8 +151,8 @@ impl CallExt2 for Call<'_> {
         engine_state: &EngineState,
         stack: &mut Stack,
     ) -> Result<Option<Locality>, LabeledError> {
-        self.get_flag::<String>(engine_state, stack, "allowed-destination")?
-            .map(|o| parse_locality(&o, self.head))
+        self.get_flag::<Spanned<String>>(engine_state, stack, "allowed-destination")?
+            .map(|o| parse_locality(&o.item, o.span))
             .transpose()
     }
 
@@ -155,8 +176,8 @@ impl CallExt2 for Call<'_> {
             })
         }
 
-        self.get_flag::<String>(engine_state, stack, "priority")?
-            .map(|p| parse_priority(&p, self.head))
+        self.get_flag::<Spanned<String>>(engine_state, stack, "priority")?
+            .map(|p| parse_priority(&p.item, p.span))
             .transpose()
     }
 
@@ -180,8 +201,8 @@ impl CallExt2 for Call<'_> {
             }
         }
 
-        self.get_flag::<String>(engine_state, stack, "consolidation")?
-            .map(|c| parse_consolidation(&c, self.head))
+        self.get_flag::<Spanned<String>>(engine_state, stack, "consolidation")?
+            .map(|c| parse_consolidation(&c.item, c.span))
             .transpose()
     }
 
@@ -204,8 +225,8 @@ impl CallExt2 for Call<'_> {
             }
         }
 
-        self.get_flag::<String>(engine_state, stack, "target")?
-            .map(|c| parse_target(&c, self.head))
+        self.get_flag::<Spanned<String>>(engine_state, stack, "target")?
+            .map(|c| parse_target(&c.item, c.span))
             .transpose()
     }
 
@@ -227,8 +248,8 @@ impl CallExt2 for Call<'_> {
             }
         }
 
-        self.get_flag::<i64>(engine_state, stack, "congestion-control")?
-            .map(|c| parse_congestion_control(c, self.head))
+        self.get_flag::<Spanned<i64>>(engine_state, stack, "congestion-control")?
+            .map(|c| parse_congestion_control(c.item, c.span))
             .transpose()
     }
 
@@ -248,8 +269,8 @@ impl CallExt2 for Call<'_> {
             })
         }
 
-        self.get_flag::<String>(engine_state, stack, "timestamp")?
-            .map(|c| parse_timestamp(&c, self.head))
+        self.get_flag::<Spanned<String>>(engine_state, stack, "timestamp")?
+            .map(|c| parse_timestamp(&c.item, c.span))
             .transpose()
     }
 
@@ -258,9 +279,16 @@ impl CallExt2 for Call<'_> {
         engine_state: &EngineState,
         stack: &mut Stack,
     ) -> Result<String, LabeledError> {
-        Ok(self
-            .get_flag::<String>(engine_state, stack, "session")?
-            .unwrap_or(State::DEFAULT_SESSION_NAME.to_string()))
+        if let Some(name) = self.get_flag::<String>(engine_state, stack, "session")? {
+            return Ok(name);
+        }
+
+        match stack.get_env_var(engine_state, State::SESSION_ENV_VAR) {
+            Some(value) => value
+                .coerce_string()
+                .map_err(|err| LabeledError::from_diagnostic(&err)),
+            None => Ok(State::DEFAULT_SESSION_NAME.to_string()),
+        }
     }
 
     fn complete(
@@ -320,6 +348,67 @@ impl CallExt2 for Call<'_> {
             None => Ok(None),
         }
     }
+
+    fn interval(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+    ) -> Result<Option<Duration>, LabeledError> {
+        match self.get_flag::<Value>(engine_state, stack, "interval")? {
+            Some(v) => Ok(Some(Duration::from_nanos(v.as_duration()? as u64))),
+            None => Ok(None),
+        }
+    }
+
+    fn size(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+    ) -> Result<Option<usize>, LabeledError> {
+        let size = match self.get_flag::<Value>(engine_state, stack, "size")? {
+            Some(Value::Filesize { val, .. }) => val.get(),
+            Some(v) => v.as_int()?,
+            None => return Ok(None),
+        };
+
+        usize::try_from(size).map(Some).map_err(|_| {
+            LabeledError::new("Invalid size").with_label("Size must be non-negative", self.head)
+        })
+    }
+
+    fn flow_control(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+    ) -> Result<FlowControl, LabeledError> {
+        let mut flow = FlowControl::default();
+
+        if let Some(buffer) = self.get_flag::<i64>(engine_state, stack, "buffer")? {
+            flow.buffer = usize::try_from(buffer)
+                .ok()
+                .filter(|buffer| *buffer > 0)
+                .ok_or_else(|| {
+                    LabeledError::new("Invalid buffer size")
+                        .with_label("Must be a positive integer", self.head)
+                })?;
+        }
+
+        if let Some(overflow) = self.get_flag::<Spanned<String>>(engine_state, stack, "overflow")? {
+            flow.overflow = match overflow.item.as_str() {
+                "block" => Overflow::Block,
+                "drop-oldest" => Overflow::DropOldest,
+                "drop-newest" => Overflow::DropNewest,
+                _ => {
+                    return Err(LabeledError::new("Invalid overflow behavior").with_label(
+                        "Must be 'block', 'drop-oldest' or 'drop-newest'",
+                        overflow.span,
+                    ))
+                }
+            };
+        }
+
+        Ok(flow)
+    }
 }
 
 /// Helper function to parse locality values
@@ -335,3 +424,43 @@ fn parse_locality(
             .with_label("Must be 'any', 'remote', or 'session-local'", span)),
     }
 }
+
+/// Accepted values of the enum flags, with a description of each, used for completions.
+pub(crate) fn flag_values(flag: &str) -> Option<&'static [(&'static str, &'static str)]> {
+    const LOCALITIES: &[(&str, &str)] = &[
+        ("any", "Local and remote entities"),
+        ("remote", "Remote entities only"),
+        ("session-local", "Entities of the same session only"),
+    ];
+
+    match flag {
+        "allowed-origin" | "allowed-destination" => Some(LOCALITIES),
+        "target" => Some(&[
+            ("all", "All matching queryables"),
+            ("all-complete", "All matching complete queryables"),
+            ("best-matching", "The best matching queryable"),
+        ]),
+        "consolidation" => Some(&[
+            ("auto", "Chosen by Zenoh"),
+            ("latest", "Only the latest reply per key"),
+            ("monotonic", "Replies with increasing timestamps per key"),
+            ("none", "All replies"),
+        ]),
+        "priority" => Some(&[
+            ("0", "Control"),
+            ("1", "Real-time"),
+            ("2", "Interactive high"),
+            ("3", "Interactive low"),
+            ("4", "Data high"),
+            ("5", "Data"),
+            ("6", "Data low"),
+            ("7", "Background"),
+        ]),
+        "overflow" => Some(&[
+            ("block", "Block the callback until the shell catches up"),
+            ("drop-oldest", "Evict the oldest buffered item"),
+            ("drop-newest", "Discard the incoming item"),
+        ]),
+        _ => None,
+    }
+}
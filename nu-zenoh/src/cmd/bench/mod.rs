@@ -0,0 +1,36 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::time::Duration;
+
+use nu_protocol::{record, IntoValue, Span, Value};
+
+pub(crate) mod publish;
+pub(crate) mod subscribe;
+
+/// Default reporting interval.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Builds a throughput report of `msgs` messages totalling `bytes` bytes over `elapsed`.
+fn throughput_record(msgs: u64, bytes: u64, elapsed: Duration, span: Span) -> Value {
+    let secs = elapsed.as_secs_f64();
+
+    record!(
+        "msgs" => (msgs as i64).into_value(span),
+        "bytes" => (bytes as i64).into_value(span),
+        "elapsed" => Value::duration(elapsed.as_nanos() as i64, span),
+        "msgs_per_sec" => (msgs as f64 / secs).into_value(span),
+        "mb_per_sec" => (bytes as f64 / 1e6 / secs).into_value(span),
+    )
+    .into_value(span)
+}
@@ -0,0 +1,188 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::time::{Duration, Instant};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, ListStream, PipelineData, ShellError, Signals, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+use zenoh::{bytes::ZBytes, pubsub::Publisher, Session, Wait};
+
+use crate::{
+    call_ext2::CallExt2,
+    cmd::bench::{throughput_record, DEFAULT_INTERVAL},
+    completion,
+    interruptible_channel::session_closed_error,
+    signature_ext::SignatureExt,
+    State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Pub {
+    state: State,
+}
+
+impl Pub {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Pub {
+    fn name(&self) -> &str {
+        "zenoh bench pub"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .keyexpr()
+            .qos()
+            .size()
+            .named(
+                "interval",
+                SyntaxShape::Duration,
+                "Reporting interval (defaults to 1sec)",
+                Some('i'),
+            )
+            .allowed_destination()
+    }
+
+    fn description(&self) -> &str {
+        "Publish as fast as possible and report the throughput"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Equivalent to Zenoh's z_pub_thr example. Streams one record per interval until interrupted."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        const DEFAULT_SIZE: usize = 8;
+
+        let span = call.head;
+
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let size = call.size(engine_state, stack)?.unwrap_or(DEFAULT_SIZE);
+        let interval = call
+            .interval(engine_state, stack)?
+            .unwrap_or(DEFAULT_INTERVAL);
+
+        let session = self
+            .state
+            .with_session(&call.session(engine_state, stack)?, Session::clone)?;
+        let publisher = {
+            let mut publisher = session.declare_publisher(keyexpr);
+
+            if let Some(priority) = call.priority(engine_state, stack)? {
+                publisher = publisher.priority(priority);
+            }
+
+            if let Some(congestion_control) = call.congestion_control(engine_state, stack)? {
+                publisher = publisher.congestion_control(congestion_control);
+            }
+
+            if let Some(reliability) = call.reliable(engine_state, stack)? {
+                publisher = publisher.reliability(reliability);
+            }
+
+            if let Some(express) = call.express(engine_state, stack)? {
+                publisher = publisher.express(express);
+            }
+
+            if let Some(destination) = call.allowed_destination(engine_state, stack)? {
+                publisher = publisher.allowed_destination(destination);
+            }
+
+            publisher.wait()
+        }
+        .map_err(|e| {
+            LabeledError::new("Publisher declaration failed")
+                .with_label(format!("Zenoh publisher failed: {e}"), span)
+        })?;
+
+        let payload = ZBytes::from((0..size).map(|i| (i % 10) as u8).collect::<Vec<_>>());
+
+        let iter = PubIter {
+            publisher,
+            session: Some(session),
+            payload,
+            size,
+            interval,
+            signals: engine_state.signals().clone(),
+            span,
+        };
+
+        Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+    }
+}
+
+struct PubIter {
+    publisher: Publisher<'static>,
+    /// Dropped once the session is found closed or a put fails, ending the stream.
+    session: Option<Session>,
+    payload: ZBytes,
+    size: usize,
+    interval: Duration,
+    signals: Signals,
+    span: Span,
+}
+
+impl Iterator for PubIter {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let session = self.session.as_ref()?;
+        if self.signals.interrupted() {
+            return None;
+        }
+
+        let start = Instant::now();
+        let mut msgs = 0;
+        while start.elapsed() < self.interval && !self.signals.interrupted() {
+            if session.is_closed() {
+                self.session = None;
+                return Some(session_closed_error(self.span));
+            }
+            if let Err(e) = self.publisher.put(self.payload.clone()).wait() {
+                self.session = None;
+                return Some(Value::error(
+                    LabeledError::new("Put operation failed")
+                        .with_label(format!("Zenoh put failed: {e}"), self.span)
+                        .into(),
+                    self.span,
+                ));
+            }
+            msgs += 1;
+        }
+
+        Some(throughput_record(
+            msgs,
+            msgs * self.size as u64,
+            start.elapsed(),
+            self.span,
+        ))
+    }
+}
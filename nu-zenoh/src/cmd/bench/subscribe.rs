@@ -0,0 +1,182 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, ListStream, PipelineData, ShellError, Signals, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+use zenoh::{pubsub::Subscriber, Session, Wait};
+
+use crate::{
+    call_ext2::CallExt2,
+    cmd::bench::{throughput_record, DEFAULT_INTERVAL},
+    completion,
+    interruptible_channel::session_closed_error,
+    signature_ext::SignatureExt,
+    State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Sub {
+    state: State,
+}
+
+impl Sub {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Sub {
+    fn name(&self) -> &str {
+        "zenoh bench sub"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .keyexpr()
+            .named(
+                "interval",
+                SyntaxShape::Duration,
+                "Reporting interval (defaults to 1sec)",
+                Some('i'),
+            )
+            .allowed_origin()
+    }
+
+    fn description(&self) -> &str {
+        "Subscribe and report the received throughput"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Equivalent to Zenoh's z_sub_thr example. Streams one record per interval until interrupted."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let interval = call
+            .interval(engine_state, stack)?
+            .unwrap_or(DEFAULT_INTERVAL);
+
+        let counters = Arc::new(Counters::default());
+
+        let session_name = call.session(engine_state, stack)?;
+        let session = self.state.with_session(&session_name, Session::clone)?;
+
+        let subscriber = self
+            .state
+            .with_session(&session_name, |sess| {
+                let counters = counters.clone();
+                let mut sub = sess.declare_subscriber(keyexpr).callback(move |sample| {
+                    counters.msgs.fetch_add(1, Ordering::Relaxed);
+                    counters
+                        .bytes
+                        .fetch_add(sample.payload().len() as u64, Ordering::Relaxed);
+                });
+
+                if let Some(origin) = call.allowed_origin(engine_state, stack)? {
+                    sub = sub.allowed_origin(origin);
+                }
+
+                sub.wait()
+            })?
+            .map_err(|e| {
+                LabeledError::new("Subscriber declaration failed")
+                    .with_label(format!("Zenoh subscriber failed: {e}"), span)
+            })?;
+
+        let iter = SubIter {
+            _subscriber: subscriber,
+            session: Some(session),
+            counters,
+            interval,
+            signals: engine_state.signals().clone(),
+            span,
+        };
+
+        Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+    }
+}
+
+#[derive(Default)]
+struct Counters {
+    msgs: AtomicU64,
+    bytes: AtomicU64,
+}
+
+struct SubIter {
+    _subscriber: Subscriber<()>,
+    /// Dropped once the session is found closed.
+    session: Option<Session>,
+    counters: Arc<Counters>,
+    interval: Duration,
+    signals: Signals,
+    span: Span,
+}
+
+impl SubIter {
+    /// Granularity at which interruptions are checked.
+    const TICK: Duration = Duration::from_millis(50);
+}
+
+impl Iterator for SubIter {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let session = self.session.as_ref()?;
+
+        let start = Instant::now();
+        let mut elapsed = Duration::ZERO;
+        while elapsed < self.interval {
+            if self.signals.interrupted() {
+                return None;
+            }
+            if session.is_closed() {
+                self.session = None;
+                return Some(session_closed_error(self.span));
+            }
+            std::thread::sleep(Self::TICK.min(self.interval - elapsed));
+            elapsed = start.elapsed();
+        }
+
+        Some(throughput_record(
+            self.counters.msgs.swap(0, Ordering::Relaxed),
+            self.counters.bytes.swap(0, Ordering::Relaxed),
+            elapsed,
+            self.span,
+        ))
+    }
+}
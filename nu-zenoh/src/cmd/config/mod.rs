@@ -16,7 +16,10 @@ use nu_protocol::{
     PipelineData, ShellError, Signature, Type, Value,
 };
 
-use crate::{call_ext2::CallExt2, signature_ext::SignatureExt, State};
+use crate::{call_ext2::CallExt2, completion, signature_ext::SignatureExt, State};
+
+pub(crate) mod new;
+pub(crate) mod validate;
 
 #[derive(Clone)]
 pub(crate) struct Config {
@@ -45,6 +48,8 @@ impl Command for Config {
         "Zenoh Configuration"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
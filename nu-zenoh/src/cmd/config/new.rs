@@ -0,0 +1,165 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::str::FromStr;
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, PipelineData, Record, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use zenoh::config::{EndPoint, WhatAmI};
+
+use crate::{conv, signature_ext::SignatureExt};
+
+#[derive(Clone)]
+pub(crate) struct New;
+
+impl Command for New {
+    fn name(&self) -> &str {
+        "zenoh config new"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::record())
+            .named(
+                "mode",
+                SyntaxShape::String,
+                "Mode (either 'peer', 'client' or 'router')",
+                Some('m'),
+            )
+            .named(
+                "listen",
+                endpoints_shape(),
+                "Endpoint(s) to listen on (e.g. 'tcp/0.0.0.0:7447')",
+                Some('l'),
+            )
+            .named(
+                "connect",
+                endpoints_shape(),
+                "Endpoint(s) to connect to (e.g. 'tcp/localhost:7447')",
+                Some('c'),
+            )
+            .switch("no-multicast-scouting", "Disable multicast scouting", None)
+            .switch("shm", "Enable shared-memory transport", None)
+    }
+
+    fn description(&self) -> &str {
+        "Create a Zenoh configuration record"
+    }
+
+    fn extra_description(&self) -> &str {
+        "The resulting record can be passed to 'zenoh session open' or 'zenoh runtime open', \
+        and can be combined with overrides using 'merge deep'."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let mut config = Record::new();
+
+        if let Some(mode) = call.get_flag::<String>(engine_state, stack, "mode")? {
+            WhatAmI::from_str(&mode).map_err(|_| {
+                LabeledError::new("Invalid mode")
+                    .with_label("Must be 'peer', 'client' or 'router'", span)
+            })?;
+            config.push("mode", mode.into_value(span));
+        }
+
+        if let Some(listen) = call.get_flag::<Value>(engine_state, stack, "listen")? {
+            let endpoints = endpoints(&listen, span)?;
+            config.push("listen", record!("endpoints" => endpoints).into_value(span));
+        }
+
+        if let Some(connect) = call.get_flag::<Value>(engine_state, stack, "connect")? {
+            let endpoints = endpoints(&connect, span)?;
+            config.push(
+                "connect",
+                record!("endpoints" => endpoints).into_value(span),
+            );
+        }
+
+        if call.has_flag(engine_state, stack, "no-multicast-scouting")? {
+            config.push(
+                "scouting",
+                record!(
+                    "multicast" => record!("enabled" => false.into_value(span)).into_value(span),
+                )
+                .into_value(span),
+            );
+        }
+
+        if call.has_flag(engine_state, stack, "shm")? {
+            config.push(
+                "transport",
+                record!(
+                    "shared_memory" => record!("enabled" => true.into_value(span)).into_value(span),
+                )
+                .into_value(span),
+            );
+        }
+
+        let config = config.into_value(span);
+
+        // Make sure the record is accepted by 'zenoh session open'
+        let json_value = conv::value_to_json_value(engine_state, &config, span, false)?;
+        zenoh::Config::from_json5(&json_value.to_string()).map_err(|e| {
+            LabeledError::new("Invalid configuration")
+                .with_label(format!("Could not build config record: {e}"), span)
+        })?;
+
+        Ok(PipelineData::Value(config, None))
+    }
+}
+
+fn endpoints_shape() -> SyntaxShape {
+    SyntaxShape::OneOf(vec![
+        SyntaxShape::String,
+        SyntaxShape::List(Box::new(SyntaxShape::String)),
+    ])
+}
+
+/// Converts a string or list of strings into a list of validated endpoints.
+fn endpoints(value: &Value, span: Span) -> Result<Value, ShellError> {
+    let endpoints = match value {
+        Value::List { vals, .. } => vals
+            .iter()
+            .map(|v| v.coerce_string())
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => vec![value.coerce_string()?],
+    };
+
+    for endpoint in endpoints.iter() {
+        EndPoint::from_str(endpoint).map_err(|e| {
+            LabeledError::new("Invalid endpoint").with_label(
+                format!("Could not parse endpoint '{endpoint}': {e}"),
+                value.span(),
+            )
+        })?;
+    }
+
+    Ok(endpoints
+        .into_iter()
+        .map(|e| e.into_value(span))
+        .collect::<Vec<_>>()
+        .into_value(span))
+}
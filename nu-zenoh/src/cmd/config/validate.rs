@@ -0,0 +1,431 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    fs,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    did_you_mean,
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+use zenoh::config::EndPoint;
+
+use crate::{conv, signature_ext::SignatureExt};
+
+#[derive(Clone)]
+pub(crate) struct Validate;
+
+impl Command for Validate {
+    fn name(&self) -> &str {
+        "zenoh config validate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .named(
+                "config-file",
+                SyntaxShape::Filepath,
+                "Path to a Zenoh configuration file",
+                None,
+            )
+            .optional(
+                "config",
+                SyntaxShape::Record(vec![]),
+                "Zenoh configuration object (see https://github.com/eclipse-zenoh/zenoh/blob/main/DEFAULT_CONFIG.json5)",
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Validate a Zenoh configuration and list its problems"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let file_path = call.get_flag::<PathBuf>(engine_state, stack, "config-file")?;
+        let config_record = call.opt::<Value>(engine_state, stack, 0)?;
+
+        let json = match (file_path, config_record) {
+            (Some(file_path), None) => {
+                let contents = fs::read_to_string(&file_path).map_err(|e| {
+                    LabeledError::new("Failed to load config file").with_label(
+                        format!("Could not read config from {}: {e}", file_path.display()),
+                        span,
+                    )
+                })?;
+                parse_config_file(&file_path, &contents).map_err(|e| {
+                    LabeledError::new("Failed to parse config file").with_label(
+                        format!("Could not parse config from {}: {e}", file_path.display()),
+                        span,
+                    )
+                })?
+            }
+            (None, Some(val @ Value::Record { .. })) => {
+                conv::value_to_json_value(engine_state, &val, span, false)?
+            }
+            (None, Some(_)) => {
+                return Err(ShellError::GenericError {
+                    error: "Invalid config type".to_string(),
+                    msg: "Config must be a record".to_string(),
+                    span: Some(span),
+                    help: Some("Provide a record with Zenoh configuration options".to_string()),
+                    inner: vec![],
+                });
+            }
+            (Some(_), Some(_)) | (None, None) => {
+                return Err(ShellError::GenericError {
+                    error: "Missing or conflicting arguments".to_string(),
+                    msg: "Exactly one of RECORD or --config-file must be specified".to_string(),
+                    span: Some(span),
+                    help: None,
+                    inner: vec![],
+                });
+            }
+        };
+
+        let problems = validate(&json)
+            .into_iter()
+            .map(|p| p.into_value(span))
+            .collect::<Vec<_>>();
+
+        Ok(PipelineData::Value(Value::list(problems, span), None))
+    }
+}
+
+/// Parses a configuration file according to its extension, as `zenoh::Config::from_file` does.
+fn parse_config_file(path: &Path, contents: &str) -> Result<nu_json::Value, String> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") | Some("json5") => json5::from_str(contents).map_err(|e| e.to_string()),
+        Some("yaml") | Some("yml") => serde_yaml::from_str(contents).map_err(|e| e.to_string()),
+        _ => Err("unsupported file type, expected .json, .json5, .yaml or .yml".to_string()),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Error,
+    Warning,
+}
+
+/// A single configuration problem, located by its JSON path (e.g. `$.listen.endpoints[0]`).
+#[derive(Debug, Clone)]
+struct Problem {
+    path: String,
+    severity: Severity,
+    message: String,
+    suggestion: Option<String>,
+}
+
+impl Problem {
+    fn error(path: &str, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_string(),
+            severity: Severity::Error,
+            message: message.into(),
+            suggestion: None,
+        }
+    }
+
+    fn warning(path: &str, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::error(path, message)
+        }
+    }
+
+    fn suggest(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    fn into_value(self, span: Span) -> Value {
+        record!(
+            "path" => self.path.into_value(span),
+            "severity" => match self.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            }.into_value(span),
+            "message" => self.message.into_value(span),
+            "suggestion" => self.suggestion.map(|s| s.into_value(span)).unwrap_or_default(),
+        )
+        .into_value(span)
+    }
+}
+
+const MODES: [&str; 3] = ["router", "peer", "client"];
+
+/// Protocols whose address is a `<host>:<port>` pair.
+const PORTED_PROTOCOLS: [&str; 5] = ["tcp", "udp", "tls", "quic", "ws"];
+
+/// Port suggested for endpoints that lack one.
+const DEFAULT_PORT: u16 = 7447;
+
+/// Returns every problem found in the given configuration.
+///
+/// Unknown keys are detected by comparing against the default configuration; sub-trees that are
+/// free-form in the default configuration (e.g. `plugins`) are not checked.
+fn validate(json: &nu_json::Value) -> Vec<Problem> {
+    let mut problems = Vec::new();
+
+    let Some(root) = json.as_object() else {
+        problems.push(Problem::error("$", "configuration must be an object"));
+        return problems;
+    };
+
+    if let Ok(reference) =
+        nu_json::from_str::<nu_json::Value>(&zenoh::Config::default().to_string())
+    {
+        check_keys("$", json, &reference, &mut problems);
+    }
+
+    let mode = match root.get("mode") {
+        None | Some(nu_json::Value::Null) => None,
+        Some(nu_json::Value::String(mode)) if MODES.contains(&mode.as_str()) => Some(mode.as_str()),
+        Some(mode) => {
+            let problem = Problem::error("$.mode", format!("invalid mode {mode}"));
+            problems.push(match mode.as_str().and_then(|m| did_you_mean(&MODES, m)) {
+                Some(suggestion) => problem.suggest(format!("use '{suggestion}'")),
+                None => problem.suggest("use one of 'router', 'peer' or 'client'"),
+            });
+            None
+        }
+    };
+
+    let listen = endpoints(root, "listen", &mut problems);
+    let connect = endpoints(root, "connect", &mut problems);
+
+    for (path, endpoint) in listen.iter() {
+        if let Some((connect_path, _)) = connect.iter().find(|(_, e)| e == endpoint) {
+            problems.push(
+                Problem::warning(
+                    path,
+                    format!("endpoint '{endpoint}' is also a connect endpoint ({connect_path})"),
+                )
+                .suggest("remove it from either listen or connect endpoints"),
+            );
+        }
+    }
+
+    let mode_listen = listen
+        .iter()
+        .filter(|(path, _)| applies_to_mode(path, mode.unwrap_or("peer")))
+        .count();
+    let mode_connect = connect
+        .iter()
+        .filter(|(path, _)| applies_to_mode(path, mode.unwrap_or("peer")))
+        .count();
+    let multicast = root
+        .get("scouting")
+        .and_then(|s| s.find("multicast"))
+        .and_then(|m| m.find("enabled"))
+        .and_then(|e| e.as_bool())
+        .unwrap_or(true);
+
+    if mode == Some("client") {
+        if mode_listen > 0 {
+            problems.push(
+                Problem::warning(
+                    "$.listen.endpoints",
+                    "clients do not accept incoming sessions from other nodes",
+                )
+                .suggest("use 'peer' or 'router' mode, or remove the listen endpoints"),
+            );
+        }
+
+        if mode_connect == 0 && !multicast {
+            problems.push(
+                Problem::error(
+                    "$.connect.endpoints",
+                    "a client with multicast scouting disabled and no connect endpoints cannot reach any router",
+                )
+                .suggest(format!("add a connect endpoint (e.g. 'tcp/localhost:{DEFAULT_PORT}')")),
+            );
+        }
+    }
+
+    if let Some(address) = root
+        .get("scouting")
+        .and_then(|s| s.find("multicast"))
+        .and_then(|m| m.find("address"))
+        .and_then(|a| a.as_str())
+        .filter(|a| SocketAddr::from_str(a).is_err())
+    {
+        problems.push(
+            Problem::error(
+                "$.scouting.multicast.address",
+                format!("invalid multicast address '{address}'"),
+            )
+            .suggest("use an '<ip>:<port>' pair (e.g. '224.0.0.224:7446')"),
+        );
+    }
+
+    // Anything our checks missed is still caught by Zenoh itself
+    let has_errors = problems.iter().any(|p| p.severity == Severity::Error);
+    match zenoh::Config::from_json5(&json.to_string()) {
+        Err(e) if !has_errors => problems.push(Problem::error("$", e.to_string())),
+        _ => {}
+    }
+
+    problems
+}
+
+/// Reports keys of `value` which don't exist in `reference`, recursively.
+fn check_keys(
+    path: &str,
+    value: &nu_json::Value,
+    reference: &nu_json::Value,
+    problems: &mut Vec<Problem>,
+) {
+    let (Some(object), Some(reference)) = (value.as_object(), reference.as_object()) else {
+        return;
+    };
+
+    if reference.is_empty() {
+        return;
+    }
+
+    let known = reference.keys().map(String::as_str).collect::<Vec<_>>();
+    let mode_dependent = known.iter().any(|k| MODES.contains(k));
+
+    for (key, child) in object.iter() {
+        let child_path = format!("{path}.{key}");
+        match reference.get(key) {
+            Some(child_reference) => check_keys(&child_path, child, child_reference, problems),
+            None if mode_dependent && MODES.contains(&key.as_str()) => {}
+            None => {
+                let problem = Problem::error(&child_path, format!("unknown key '{key}'"));
+                problems.push(match did_you_mean(&known, key) {
+                    Some(suggestion) => problem.suggest(format!("did you mean '{suggestion}'?")),
+                    None => problem,
+                });
+            }
+        }
+    }
+}
+
+/// Collects and checks the endpoints under `<section>.endpoints`, returning them with their paths.
+///
+/// Endpoints are either a list or a record of lists keyed by mode.
+fn endpoints(
+    root: &nu_json::Map<String, nu_json::Value>,
+    section: &str,
+    problems: &mut Vec<Problem>,
+) -> Vec<(String, String)> {
+    let base = format!("$.{section}.endpoints");
+    let mut lists = Vec::new();
+
+    match root.get(section).and_then(|s| s.find("endpoints")) {
+        None | Some(nu_json::Value::Null) => {}
+        Some(nu_json::Value::Array(list)) => lists.push((base, list)),
+        Some(nu_json::Value::Object(per_mode)) => {
+            for (mode, list) in per_mode.iter() {
+                let path = format!("{base}.{mode}");
+                if !MODES.contains(&mode.as_str()) {
+                    let problem = Problem::error(&path, format!("unknown mode '{mode}'"));
+                    problems.push(match did_you_mean(&MODES, mode) {
+                        Some(suggestion) => {
+                            problem.suggest(format!("did you mean '{suggestion}'?"))
+                        }
+                        None => problem.suggest("use one of 'router', 'peer' or 'client'"),
+                    });
+                    continue;
+                }
+                match list {
+                    nu_json::Value::Array(list) => lists.push((path, list)),
+                    _ => problems.push(Problem::error(&path, "endpoints must be a list")),
+                }
+            }
+        }
+        Some(_) => problems.push(Problem::error(
+            &base,
+            "endpoints must be a list or a record of lists keyed by mode",
+        )),
+    }
+
+    let mut endpoints = Vec::new();
+    for (path, list) in lists {
+        for (i, endpoint) in list.iter().enumerate() {
+            let path = format!("{path}[{i}]");
+            let Some(endpoint) = endpoint.as_str() else {
+                problems.push(Problem::error(&path, "endpoint must be a string"));
+                continue;
+            };
+            if let Some(problem) = check_endpoint(&path, endpoint) {
+                problems.push(problem);
+            }
+            endpoints.push((path, endpoint.to_string()));
+        }
+    }
+
+    endpoints
+}
+
+/// Checks a single endpoint string (e.g. `tcp/127.0.0.1:7447`).
+fn check_endpoint(path: &str, endpoint: &str) -> Option<Problem> {
+    let Some((protocol, _)) = endpoint.split_once('/') else {
+        return Some(
+            Problem::error(path, format!("endpoint '{endpoint}' has no protocol"))
+                .suggest(format!("tcp/{endpoint}")),
+        );
+    };
+
+    let parsed = match EndPoint::from_str(endpoint) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return Some(Problem::error(
+                path,
+                format!("invalid endpoint '{endpoint}': {e}"),
+            ));
+        }
+    };
+
+    let address = parsed.address().as_str();
+    if PORTED_PROTOCOLS.contains(&protocol) && !has_port(address) {
+        let fixed = endpoint.replacen(address, &format!("{address}:{DEFAULT_PORT}"), 1);
+        return Some(
+            Problem::error(path, format!("endpoint '{endpoint}' is missing a port")).suggest(fixed),
+        );
+    }
+
+    None
+}
+
+/// Returns true if the given `<host>:<port>` address has a valid port.
+fn has_port(address: &str) -> bool {
+    let port = if address.starts_with('[') {
+        address.rsplit_once("]:").map(|(_, port)| port)
+    } else {
+        address.rsplit_once(':').map(|(_, port)| port)
+    };
+    port.is_some_and(|p| p.parse::<u16>().is_ok())
+}
+
+/// Returns true if the endpoint path applies to the given mode.
+fn applies_to_mode(path: &str, mode: &str) -> bool {
+    !MODES.iter().any(|m| path.contains(&format!(".{m}["))) || path.contains(&format!(".{mode}["))
+}
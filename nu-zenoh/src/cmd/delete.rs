@@ -18,7 +18,7 @@ use nu_protocol::{
 };
 use zenoh::Wait;
 
-use crate::{call_ext2::CallExt2, signature_ext::SignatureExt, State};
+use crate::{call_ext2::CallExt2, completion, signature_ext::SignatureExt, State};
 
 #[derive(Clone)]
 pub(crate) struct Delete {
@@ -47,6 +47,8 @@ impl Command for Delete {
         "Zenoh DELETE"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
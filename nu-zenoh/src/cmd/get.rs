@@ -13,11 +13,11 @@
 //
 use nu_engine::CallExt;
 use nu_protocol::{engine, ListStream, PipelineData, ShellError, Signature, SyntaxShape, Type};
-use zenoh::Wait;
+use zenoh::{Session, Wait};
 
 use crate::{
-    call_ext2::CallExt2, conv, interruptible_channel::InterruptibleChannel,
-    signature_ext::SignatureExt, State,
+    call_ext2::CallExt2, completion, conv, flow_control,
+    interruptible_channel::InterruptibleChannel, signature_ext::SignatureExt, State,
 };
 
 #[derive(Clone)]
@@ -59,12 +59,15 @@ impl engine::Command for Get {
             .named("encoding", SyntaxShape::String, "Query encoding", None)
             .named("attachment", SyntaxShape::String, "Query attachment", None)
             .allowed_destination()
+            .flow_control()
     }
 
     fn description(&self) -> &str {
         "Zenoh GET"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &engine::EngineState,
@@ -74,16 +77,16 @@ impl engine::Command for Get {
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
 
-        const REPLY_CHANNEL_SIZE: usize = 256;
-        let (tx, rx) = flume::bounded(REPLY_CHANNEL_SIZE);
+        let (tx, rx, dropped) = flow_control::channel(call.flow_control(engine_state, stack)?);
+
+        let session_name = call.session(engine_state, stack)?;
+        let session = self.state.with_session(&session_name, Session::clone)?;
 
         self.state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
+            .with_session(&session_name, |sess| {
                 let mut get = sess
                     .get(call.req::<String>(engine_state, stack, 0)?)
-                    .callback(move |reply| {
-                        let _ = tx.send(reply);
-                    });
+                    .callback(move |reply| tx.send(reply));
 
                 if let Some(target) = call.target(engine_state, stack)? {
                     get = get.target(target);
@@ -121,13 +124,20 @@ impl engine::Command for Get {
                     .with_label(format!("Zenoh get failed: {e}"), call.head)
             })?;
 
-        let iter =
-            InterruptibleChannel::new(rx, engine_state.signals().clone()).map(move |reply| {
-                match reply.into_result() {
-                    Ok(sample) => conv::sample_to_record_value(sample, span),
-                    Err(reply_error) => conv::reply_error_to_error_value(reply_error, span),
+        let (replies, closed) =
+            InterruptibleChannel::new(rx, engine_state.signals().clone()).watch_session(session);
+
+        let mut recorder = self.state.recent_keys.recorder(&session_name);
+        let iter = replies
+            .map(move |reply| match reply.into_result() {
+                Ok(sample) => {
+                    recorder.record(sample.key_expr().as_str());
+                    conv::sample_to_record_value(sample, span)
                 }
-            });
+                Err(reply_error) => conv::reply_error_to_error_value(reply_error, span),
+            })
+            .chain(dropped.report(span))
+            .chain(closed.report(span));
 
         Ok(ListStream::new(iter, call.head, engine_state.signals().clone()).into())
     }
@@ -17,7 +17,7 @@ use nu_protocol::{
 };
 use zenoh::Wait;
 
-use crate::{call_ext2::CallExt2, signature_ext::SignatureExt, State};
+use crate::{call_ext2::CallExt2, completion, signature_ext::SignatureExt, State};
 
 #[derive(Clone)]
 pub(crate) struct Info {
@@ -46,6 +46,8 @@ impl Command for Info {
         "Session information"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -0,0 +1,55 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, ShellError, Signature, Type, Value,
+};
+use zenoh::key_expr::OwnedKeyExpr;
+
+use crate::cmd::keyexpr::{invalid, map_unary, unary_signature};
+
+#[derive(Clone)]
+pub(crate) struct Canonize;
+
+impl Command for Canonize {
+    fn name(&self) -> &str {
+        "zenoh keyexpr canonize"
+    }
+
+    fn signature(&self) -> Signature {
+        unary_signature(self.name(), Type::String)
+    }
+
+    fn description(&self) -> &str {
+        "Returns the canonical form of a keyexpr"
+    }
+
+    fn extra_description(&self) -> &str {
+        "For example, 'a/**/**/b' becomes 'a/**/b' and 'a/**/*' becomes 'a/*/**'."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        map_unary(engine_state, stack, call, input, |keyexpr, span| {
+            OwnedKeyExpr::autocanonize(keyexpr.to_string())
+                .map(|keyexpr| Value::string(keyexpr.to_string(), span))
+                .map_err(|err| invalid("Invalid key-expression", err, span))
+        })
+    }
+}
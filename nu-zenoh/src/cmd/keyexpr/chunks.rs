@@ -0,0 +1,52 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, ShellError, Signature, Type, Value,
+};
+
+use crate::cmd::keyexpr::{map_unary, parse, unary_signature};
+
+#[derive(Clone)]
+pub(crate) struct Chunks;
+
+impl Command for Chunks {
+    fn name(&self) -> &str {
+        "zenoh keyexpr chunks"
+    }
+
+    fn signature(&self) -> Signature {
+        unary_signature(self.name(), Type::list(Type::String))
+    }
+
+    fn description(&self) -> &str {
+        "Splits a keyexpr into its chunks"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        map_unary(engine_state, stack, call, input, |keyexpr, span| {
+            let chunks = parse(keyexpr, span)?
+                .chunks()
+                .map(|chunk| Value::string(chunk.as_str(), span))
+                .collect();
+            Ok(Value::list(chunks, span))
+        })
+    }
+}
@@ -0,0 +1,83 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, PipelineData, ShellError, Signature, Type, Value,
+};
+use zenoh::key_expr::keyexpr_tree::IKeyExprTree;
+
+use crate::cmd::keyexpr::collect_tree;
+
+#[derive(Clone)]
+pub(crate) struct Cover;
+
+impl Command for Cover {
+    fn name(&self) -> &str {
+        "zenoh keyexpr cover"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::list(Type::String), Type::table())])
+    }
+
+    fn description(&self) -> &str {
+        "Returns the minimal set of keyexprs including all input keyexprs"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Each row holds a keyexpr of the cover and the other input keyexprs it includes."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let (keys, tree) = collect_tree(call, input)?;
+
+        let rows = keys
+            .iter()
+            .filter(|keyexpr| {
+                !tree
+                    .keys_including(keyexpr)
+                    .any(|other| other.as_str() != keyexpr.as_str())
+            })
+            .map(|keyexpr| {
+                let mut covers = tree
+                    .included_keys(keyexpr)
+                    .filter(|other| other.as_str() != keyexpr.as_str())
+                    .map(|other| other.to_string())
+                    .collect::<Vec<_>>();
+                covers.sort();
+
+                Value::record(
+                    record! {
+                        "keyexpr" => Value::string(keyexpr.as_str(), span),
+                        "covers" => Value::list(
+                            covers.into_iter().map(|k| Value::string(k, span)).collect(),
+                            span,
+                        ),
+                    },
+                    span,
+                )
+            })
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(rows, span), None))
+    }
+}
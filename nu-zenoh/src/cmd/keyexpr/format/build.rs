@@ -0,0 +1,121 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, PipelineData, Record, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value,
+};
+
+use crate::cmd::keyexpr::{
+    format::{check_spec, parse_spec},
+    invalid,
+};
+
+#[derive(Clone)]
+pub(crate) struct Build;
+
+impl Command for Build {
+    fn name(&self) -> &str {
+        "zenoh keyexpr format build"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::String),
+                (Type::record(), Type::String),
+                (Type::list(Type::record()), Type::list(Type::String)),
+            ])
+            .required(
+                "spec",
+                SyntaxShape::String,
+                "Key-expression format (e.g. 'robot/${id:*}/sensor/${kind:*}').",
+            )
+            .optional(
+                "fields",
+                SyntaxShape::Record(vec![]),
+                "Field values (defaults to the input).",
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Builds a keyexpr from a keyexpr format and field values"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Missing or null fields take their default value from the format, if any."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let spec = call.req::<Spanned<String>>(engine_state, stack, 0)?;
+        check_spec(&spec)?;
+
+        if let Some(fields) = call.opt::<Value>(engine_state, stack, 1)? {
+            let span = fields.span();
+            return Ok(PipelineData::Value(
+                build_key(&spec, fields.as_record()?, span)?,
+                None,
+            ));
+        }
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(LabeledError::new("Missing fields")
+                .with_label("Expected a record argument or input", call.head)
+                .into());
+        }
+
+        input.map(
+            move |value| {
+                let span = value.span();
+                match value
+                    .as_record()
+                    .and_then(|fields| build_key(&spec, fields, span))
+                {
+                    Ok(value) => value,
+                    Err(err) => Value::error(err, span),
+                }
+            },
+            engine_state.signals(),
+        )
+    }
+}
+
+fn build_key(spec: &Spanned<String>, fields: &Record, span: Span) -> Result<Value, ShellError> {
+    let format = parse_spec(&spec.item, spec.span)?;
+    let mut formatter = format.formatter();
+
+    for (id, value) in fields.iter() {
+        if value.is_nothing() {
+            continue;
+        }
+
+        let value_span = value.span();
+        let value = value.coerce_string()?;
+        formatter
+            .set(id, &value)
+            .map_err(|err| invalid(&format!("Invalid value for field '{id}'"), err, value_span))?;
+    }
+
+    formatter
+        .build()
+        .map(|keyexpr| Value::string(keyexpr.to_string(), span))
+        .map_err(|err| invalid("Failed to build key-expression", err, span))
+}
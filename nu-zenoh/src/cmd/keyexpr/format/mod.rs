@@ -0,0 +1,29 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{ShellError, Span, Spanned};
+use zenoh::key_expr::format::KeFormat;
+
+use crate::cmd::keyexpr::invalid;
+
+pub(crate) mod build;
+pub(crate) mod parse;
+
+/// Checks a key-expression format (e.g. 'robot/${id:*}/sensor/${kind:*}') upfront.
+fn check_spec(spec: &Spanned<String>) -> Result<(), ShellError> {
+    parse_spec(&spec.item, spec.span).map(|_| ())
+}
+
+fn parse_spec(spec: &str, span: Span) -> Result<KeFormat<'_>, ShellError> {
+    KeFormat::new(spec).map_err(|err| invalid("Invalid key-expression format", err, span))
+}
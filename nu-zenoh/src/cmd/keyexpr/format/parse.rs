@@ -0,0 +1,103 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, Record, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+use zenoh::key_expr::keyexpr;
+
+use crate::cmd::keyexpr::{
+    format::{check_spec, parse_spec},
+    invalid, map_input,
+};
+
+#[derive(Clone)]
+pub(crate) struct Parse;
+
+impl Command for Parse {
+    fn name(&self) -> &str {
+        "zenoh keyexpr format parse"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::record()),
+                (Type::String, Type::record()),
+                (Type::list(Type::String), Type::list(Type::record())),
+            ])
+            .required(
+                "spec",
+                SyntaxShape::String,
+                "Key-expression format (e.g. 'robot/${id:*}/sensor/${kind:*}').",
+            )
+            .optional(
+                "keyexpr",
+                SyntaxShape::String,
+                "Key-expression to parse (defaults to the input).",
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Returns the fields captured by a keyexpr format"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Fields that capture nothing (e.g. an empty '${rest:**}') are null."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let spec = call.req::<Spanned<String>>(engine_state, stack, 0)?;
+        check_spec(&spec)?;
+
+        match call.opt::<Spanned<String>>(engine_state, stack, 1)? {
+            Some(key) => Ok(PipelineData::Value(
+                parse_key(&spec, &key.item, key.span)?,
+                None,
+            )),
+            None => map_input(engine_state, call, input, move |key, span| {
+                parse_key(&spec, key, span)
+            }),
+        }
+    }
+}
+
+fn parse_key(spec: &Spanned<String>, key: &str, span: Span) -> Result<Value, ShellError> {
+    let format = parse_spec(&spec.item, spec.span)?;
+    let key = keyexpr::new(key).map_err(|err| invalid("Invalid key-expression", err, span))?;
+    let parsed = format
+        .parse(key)
+        .map_err(|err| invalid("Key-expression does not match the format", err, span))?;
+
+    let fields = parsed
+        .iter()
+        .map(|(id, value)| {
+            (
+                id.to_string(),
+                value
+                    .map(|value| Value::string(value.as_str(), span))
+                    .unwrap_or_default(),
+            )
+        })
+        .collect::<Record>();
+
+    Ok(Value::record(fields, span))
+}
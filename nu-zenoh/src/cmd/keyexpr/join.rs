@@ -0,0 +1,54 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, ShellError, Signature, Type, Value,
+};
+
+use crate::cmd::keyexpr::{binary_signature, invalid, map_binary, BINARY_EXTRA_DESCRIPTION};
+
+#[derive(Clone)]
+pub(crate) struct Join;
+
+impl Command for Join {
+    fn name(&self) -> &str {
+        "zenoh keyexpr join"
+    }
+
+    fn signature(&self) -> Signature {
+        binary_signature(self.name(), Type::String)
+    }
+
+    fn description(&self) -> &str {
+        "Joins two keyexprs with a '/' separator"
+    }
+
+    fn extra_description(&self) -> &str {
+        BINARY_EXTRA_DESCRIPTION
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        map_binary(engine_state, stack, call, input, |lhs, rhs, span| {
+            lhs.join(rhs.as_str())
+                .map(|keyexpr| Value::string(keyexpr.to_string(), span))
+                .map_err(|err| invalid("Invalid joined key-expression", err, span))
+        })
+    }
+}
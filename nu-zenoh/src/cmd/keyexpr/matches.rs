@@ -0,0 +1,99 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::str::FromStr;
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type, Value,
+};
+use zenoh::key_expr::{
+    keyexpr_tree::{IKeyExprTree, IKeyExprTreeMut, KeBoxTree},
+    KeyExpr,
+};
+
+use crate::cmd::keyexpr::parse;
+
+#[derive(Clone)]
+pub(crate) struct Match;
+
+impl Command for Match {
+    fn name(&self) -> &str {
+        "zenoh keyexpr match"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::list(Type::String), Type::list(Type::String)),
+                (Type::table(), Type::table()),
+            ])
+            .required(
+                "pattern",
+                SyntaxShape::String,
+                "Key-expression the keys must be included in.",
+            )
+            .rest(
+                "patterns",
+                SyntaxShape::String,
+                "Additional key-expressions, any of which may include the keys.",
+            )
+            .named(
+                "column",
+                SyntaxShape::String,
+                "Column holding the keys of table rows (defaults to 'keyexpr')",
+                Some('c'),
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Filters the input keys included in any of the given keyexprs"
+    }
+
+    fn extra_description(&self) -> &str {
+        "The input is either a list of keys or a table, such as the output of 'zenoh sub'. \
+        Keys that are not valid key-expressions never match."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let first = call.req::<Spanned<String>>(engine_state, stack, 0)?;
+        let rest = call.rest::<Spanned<String>>(engine_state, stack, 1)?;
+        let column = call
+            .get_flag::<String>(engine_state, stack, "column")?
+            .unwrap_or_else(|| "keyexpr".to_string());
+
+        let mut patterns = KeBoxTree::new();
+        for pattern in std::iter::once(first).chain(rest) {
+            patterns.insert(&parse(&pattern.item, pattern.span)?, ());
+        }
+
+        input.filter(
+            move |value| {
+                let key = match value {
+                    Value::Record { val, .. } => val.get(&column).and_then(|v| v.as_str().ok()),
+                    value => value.as_str().ok(),
+                };
+                key.and_then(|key| KeyExpr::from_str(key).ok())
+                    .is_some_and(|key| patterns.keys_including(&key).next().is_some())
+            },
+            engine_state.signals(),
+        )
+    }
+}
@@ -0,0 +1,256 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{fmt::Display, str::FromStr};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+use zenoh::key_expr::{
+    keyexpr_tree::{IKeyExprTree, IKeyExprTreeMut, KeBoxTree},
+    KeyExpr,
+};
+
+pub(crate) mod canonize;
+pub(crate) mod chunks;
+pub(crate) mod concat;
+pub(crate) mod cover;
+pub(crate) mod format;
+pub(crate) mod join;
+pub(crate) mod matches;
+pub(crate) mod overlap;
+pub(crate) mod relation;
+pub(crate) mod validate;
+
+/// Extra description shared by commands taking two key-expressions.
+const BINARY_EXTRA_DESCRIPTION: &str =
+    "When given a single argument, it is used as the right-hand side and the left-hand sides \
+    are read from the input (a string or a list of strings).";
+
+#[derive(Clone)]
+pub(crate) struct Includes;
+
+impl Command for Includes {
+    fn name(&self) -> &str {
+        "zenoh keyexpr includes"
+    }
+
+    fn signature(&self) -> Signature {
+        binary_signature(self.name(), Type::Bool)
+    }
+
+    fn description(&self) -> &str {
+        "Returns true if the lhs keyexpr includes the rhs keyexpr"
+    }
+
+    fn extra_description(&self) -> &str {
+        BINARY_EXTRA_DESCRIPTION
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        map_binary(engine_state, stack, call, input, |lhs, rhs, span| {
+            Ok(Value::bool(lhs.includes(&rhs), span))
+        })
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Intersects;
+
+impl Command for Intersects {
+    fn name(&self) -> &str {
+        "zenoh keyexpr intersects"
+    }
+
+    fn signature(&self) -> Signature {
+        binary_signature(self.name(), Type::Bool)
+    }
+
+    fn description(&self) -> &str {
+        "Returns true if the lhs and ths keyexprs intersect"
+    }
+
+    fn extra_description(&self) -> &str {
+        BINARY_EXTRA_DESCRIPTION
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        map_binary(engine_state, stack, call, input, |lhs, rhs, span| {
+            Ok(Value::bool(lhs.intersects(&rhs), span))
+        })
+    }
+}
+
+/// Signature of commands taking an optional key-expression, or strings as input.
+fn unary_signature(name: &str, output: Type) -> Signature {
+    Signature::build(name)
+        .input_output_types(vec![
+            (Type::Nothing, output.clone()),
+            (Type::String, output.clone()),
+            (Type::list(Type::String), Type::list(output)),
+        ])
+        .optional(
+            "keyexpr",
+            SyntaxShape::String,
+            "Key-expression (defaults to the input).",
+        )
+}
+
+/// Signature of commands taking two key-expressions, the left-hand side possibly as input.
+fn binary_signature(name: &str, output: Type) -> Signature {
+    Signature::build(name)
+        .input_output_types(vec![
+            (Type::Nothing, output.clone()),
+            (Type::String, output.clone()),
+            (Type::list(Type::String), Type::list(output)),
+        ])
+        .required("lhs", SyntaxShape::String, "Left-hand side key-expreesion.")
+        .optional(
+            "rhs",
+            SyntaxShape::String,
+            "Right-hand side key-expreesion.",
+        )
+}
+
+/// Applies `f` to the positional string, or to each string of the input if there is none.
+fn map_unary<F>(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    mut f: F,
+) -> Result<PipelineData, ShellError>
+where
+    F: FnMut(&str, Span) -> Result<Value, ShellError> + Send + 'static,
+{
+    match call.opt::<Spanned<String>>(engine_state, stack, 0)? {
+        Some(arg) => Ok(PipelineData::Value(f(&arg.item, arg.span)?, None)),
+        None => map_input(engine_state, call, input, f),
+    }
+}
+
+/// Applies `f` to two parsed key-expressions, the left-hand sides possibly coming from the input.
+fn map_binary<F>(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    mut f: F,
+) -> Result<PipelineData, ShellError>
+where
+    F: FnMut(KeyExpr<'static>, KeyExpr<'static>, Span) -> Result<Value, ShellError>
+        + Send
+        + 'static,
+{
+    let first = call.req::<Spanned<String>>(engine_state, stack, 0)?;
+
+    match call.opt::<Spanned<String>>(engine_state, stack, 1)? {
+        Some(second) => {
+            let lhs = parse_side(&first.item, "left-hand side", first.span)?;
+            let rhs = parse_side(&second.item, "right-hand side", second.span)?;
+            Ok(PipelineData::Value(f(lhs, rhs, call.head)?, None))
+        }
+        None => {
+            let rhs = parse_side(&first.item, "right-hand side", first.span)?;
+            map_input(engine_state, call, input, move |lhs, span| {
+                f(parse_side(lhs, "left-hand side", span)?, rhs.clone(), span)
+            })
+        }
+    }
+}
+
+/// Applies `f` to each string of the input.
+fn map_input<F>(
+    engine_state: &EngineState,
+    call: &Call,
+    input: PipelineData,
+    mut f: F,
+) -> Result<PipelineData, ShellError>
+where
+    F: FnMut(&str, Span) -> Result<Value, ShellError> + Send + 'static,
+{
+    if matches!(input, PipelineData::Empty) {
+        return Err(LabeledError::new("Missing key-expression")
+            .with_label("Expected a key-expression argument or input", call.head)
+            .into());
+    }
+
+    input.map(
+        move |value| {
+            let span = value.span();
+            match value.coerce_str().and_then(|s| f(&s, span)) {
+                Ok(value) => value,
+                Err(err) => Value::error(err, span),
+            }
+        },
+        engine_state.signals(),
+    )
+}
+
+/// Collects the key-expressions of the input into a tree, skipping duplicates.
+///
+/// Returns the distinct key-expressions in input order, each weighted by its index in the tree.
+fn collect_tree(
+    call: &Call,
+    input: PipelineData,
+) -> Result<(Vec<KeyExpr<'static>>, KeBoxTree<usize>), ShellError> {
+    if matches!(input, PipelineData::Empty) {
+        return Err(LabeledError::new("Missing key-expressions")
+            .with_label("Expected a list of key-expressions as input", call.head)
+            .into());
+    }
+
+    let mut keys = Vec::new();
+    let mut tree = KeBoxTree::new();
+
+    for value in input {
+        let span = value.span();
+        let keyexpr = parse(&value.coerce_into_string()?, span)?;
+        if tree.weight_at(&keyexpr).is_none() {
+            tree.insert(&keyexpr, keys.len());
+            keys.push(keyexpr);
+        }
+    }
+
+    Ok((keys, tree))
+}
+
+/// Parses a key-expression.
+fn parse(keyexpr: &str, span: Span) -> Result<KeyExpr<'static>, ShellError> {
+    KeyExpr::from_str(keyexpr).map_err(|err| invalid("Invalid key-expression", err, span))
+}
+
+fn parse_side(keyexpr: &str, side: &str, span: Span) -> Result<KeyExpr<'static>, ShellError> {
+    KeyExpr::from_str(keyexpr)
+        .map_err(|err| invalid(&format!("Invalid {side} key-expression"), err, span))
+}
+
+fn invalid(msg: &str, err: impl Display, span: Span) -> ShellError {
+    LabeledError::new(msg)
+        .with_label(err.to_string(), span)
+        .into()
+}
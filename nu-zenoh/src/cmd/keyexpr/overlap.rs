@@ -0,0 +1,85 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, PipelineData, ShellError, Signature, Type, Value,
+};
+use zenoh::key_expr::keyexpr_tree::IKeyExprTree;
+
+use crate::cmd::keyexpr::{collect_tree, relation::relation};
+
+#[derive(Clone)]
+pub(crate) struct Overlap;
+
+impl Command for Overlap {
+    fn name(&self) -> &str {
+        "zenoh keyexpr overlap"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::list(Type::String), Type::table())])
+    }
+
+    fn description(&self) -> &str {
+        "Returns each pair of intersecting keyexprs of the input"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Each pair is reported once, in input order, along with the relation of the lhs to the \
+        rhs ('includes', 'intersects', or 'included' when the rhs includes the lhs)."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let (keys, tree) = collect_tree(call, input)?;
+
+        let mut pairs = Vec::new();
+        for (i, lhs) in keys.iter().enumerate() {
+            let mut others = tree
+                .intersecting_keys(lhs)
+                .filter_map(|other| tree.weight_at(&other).copied())
+                .filter(|&j| j > i)
+                .collect::<Vec<_>>();
+            others.sort_unstable();
+            pairs.extend(others.into_iter().map(|j| (lhs, &keys[j])));
+        }
+
+        let rows = pairs
+            .into_iter()
+            .map(|(lhs, rhs)| {
+                let relation = match relation(lhs, rhs) {
+                    "intersects" if rhs.includes(lhs) => "included",
+                    relation => relation,
+                };
+                Value::record(
+                    record! {
+                        "lhs" => Value::string(lhs.as_str(), span),
+                        "rhs" => Value::string(rhs.as_str(), span),
+                        "relation" => Value::string(relation, span),
+                    },
+                    span,
+                )
+            })
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(rows, span), None))
+    }
+}
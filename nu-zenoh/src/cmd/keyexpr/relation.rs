@@ -0,0 +1,65 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    PipelineData, ShellError, Signature, Type, Value,
+};
+use zenoh::key_expr::{keyexpr, SetIntersectionLevel};
+
+use crate::cmd::keyexpr::{binary_signature, map_binary, BINARY_EXTRA_DESCRIPTION};
+
+#[derive(Clone)]
+pub(crate) struct Relation;
+
+impl Command for Relation {
+    fn name(&self) -> &str {
+        "zenoh keyexpr relation"
+    }
+
+    fn signature(&self) -> Signature {
+        binary_signature(self.name(), Type::String)
+    }
+
+    fn description(&self) -> &str {
+        "Returns the relation of the lhs keyexpr to the rhs keyexpr"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Either 'equals', 'includes' (the lhs includes the rhs), 'intersects' or 'disjoint'. \
+        When given a single argument, it is used as the right-hand side and the left-hand sides \
+        are read from the input (a string or a list of strings)."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        map_binary(engine_state, stack, call, input, |lhs, rhs, span| {
+            Ok(Value::string(relation(&lhs, &rhs), span))
+        })
+    }
+}
+
+/// Returns the name of the relation of `lhs` to `rhs`.
+pub(super) fn relation(lhs: &keyexpr, rhs: &keyexpr) -> &'static str {
+    match lhs.relation_to(rhs) {
+        SetIntersectionLevel::Equals => "equals",
+        SetIntersectionLevel::Includes => "includes",
+        SetIntersectionLevel::Intersects => "intersects",
+        SetIntersectionLevel::Disjoint => "disjoint",
+    }
+}
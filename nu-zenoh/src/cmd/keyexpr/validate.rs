@@ -0,0 +1,65 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::str::FromStr;
+
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, PipelineData, ShellError, Signature, Type, Value,
+};
+use zenoh::key_expr::{KeyExpr, OwnedKeyExpr};
+
+use crate::cmd::keyexpr::{map_unary, unary_signature};
+
+#[derive(Clone)]
+pub(crate) struct Validate;
+
+impl Command for Validate {
+    fn name(&self) -> &str {
+        "zenoh keyexpr validate"
+    }
+
+    fn signature(&self) -> Signature {
+        unary_signature(self.name(), Type::record())
+    }
+
+    fn description(&self) -> &str {
+        "Checks whether a keyexpr is valid and canonical"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Returns a record with the key-expression, whether it is valid, the reason for rejection \
+        (if any) and its canonical form (if it can be canonized)."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        map_unary(engine_state, stack, call, input, |keyexpr, span| {
+            let reason = KeyExpr::from_str(keyexpr).err();
+            let canonical = OwnedKeyExpr::autocanonize(keyexpr.to_string()).ok();
+
+            Ok(record!(
+                "keyexpr" => Value::string(keyexpr, span),
+                "valid" => reason.is_none().into_value(span),
+                "reason" => reason.map(|r| r.to_string().into_value(span)).unwrap_or_default(),
+                "canonical" => canonical.map(|c| c.to_string().into_value(span)).unwrap_or_default(),
+            )
+            .into_value(span))
+        })
+    }
+}
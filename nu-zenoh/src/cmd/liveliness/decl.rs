@@ -11,20 +11,18 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use std::{
-    str::FromStr,
-    sync::{Arc, Mutex},
-};
+use std::str::FromStr;
 
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
     PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
 };
-use zenoh::{key_expr::OwnedKeyExpr, Wait};
+use zenoh::{key_expr::OwnedKeyExpr, Session, Wait};
 
 use crate::{
-    call_ext2::CallExt2, cmd::liveliness::LivelinessTokenValue, signature_ext::SignatureExt, State,
+    call_ext2::CallExt2, cmd::liveliness::LivelinessTokenValue, completion,
+    signature_ext::SignatureExt, State,
 };
 
 #[derive(Clone)]
@@ -56,6 +54,8 @@ impl Command for Decl {
         "Zenoh liveliness token declaration"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -65,11 +65,16 @@ impl Command for Decl {
     ) -> Result<PipelineData, ShellError> {
         let keyexpr = call.req::<String>(engine_state, stack, 0)?;
 
+        let session_name = call.session(engine_state, stack)?;
+        let session = self.state.with_session(&session_name, Session::clone)?;
+
         let token = self
             .state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
-                sess.liveliness().declare_token(&keyexpr).wait()
-            })?
+            .declarations
+            .declare(&session_name, &session, "token", keyexpr.clone(), {
+                let keyexpr = keyexpr.clone();
+                move |sess| sess.liveliness().declare_token(&keyexpr).wait()
+            })
             .map_err(|e| {
                 nu_protocol::LabeledError::new("Liveliness token declaration failed").with_label(
                     format!("Zenoh Liveliness token declaration failed: {e}"),
@@ -80,7 +85,7 @@ impl Command for Decl {
         Ok(PipelineData::Value(
             Value::custom(
                 Box::new(LivelinessTokenValue {
-                    handle: Arc::new(Mutex::new(Some(token))),
+                    declaration: token,
                     keyexpr: OwnedKeyExpr::from_str(&keyexpr).unwrap(),
                 }),
                 call.head,
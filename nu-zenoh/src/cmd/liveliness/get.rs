@@ -13,10 +13,10 @@
 //
 use nu_engine::CallExt;
 use nu_protocol::{engine, ListStream, PipelineData, ShellError, Signature, SyntaxShape, Type};
-use zenoh::Wait;
+use zenoh::{Session, Wait};
 
 use crate::{
-    call_ext2::CallExt2, conv, interruptible_channel::InterruptibleChannel,
+    call_ext2::CallExt2, completion, conv, interruptible_channel::InterruptibleChannel,
     signature_ext::SignatureExt, State,
 };
 
@@ -48,6 +48,8 @@ impl engine::Command for Get {
         "Zenoh liveliness GET"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &engine::EngineState,
@@ -60,8 +62,11 @@ impl engine::Command for Get {
         const REPLY_CHANNEL_SIZE: usize = 256;
         let (tx, rx) = flume::bounded(REPLY_CHANNEL_SIZE);
 
+        let session_name = call.session(engine_state, stack)?;
+        let session = self.state.with_session(&session_name, Session::clone)?;
+
         self.state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
+            .with_session(&session_name, |sess| {
                 let mut get = sess
                     .liveliness()
                     .get(call.req::<String>(engine_state, stack, 0)?)
@@ -82,13 +87,15 @@ impl engine::Command for Get {
                     .with_label(format!("Zenoh liveliness get failed: {e}"), call.head)
             })?;
 
-        let iter =
-            InterruptibleChannel::new(rx, engine_state.signals().clone()).map(move |reply| {
-                match reply.into_result() {
-                    Ok(sample) => conv::sample_to_record_value(sample, span),
-                    Err(reply_error) => conv::reply_error_to_error_value(reply_error, span),
-                }
-            });
+        let (replies, closed) =
+            InterruptibleChannel::new(rx, engine_state.signals().clone()).watch_session(session);
+
+        let iter = replies
+            .map(move |reply| match reply.into_result() {
+                Ok(sample) => conv::sample_to_record_value(sample, span),
+                Err(reply_error) => conv::reply_error_to_error_value(reply_error, span),
+            })
+            .chain(closed.report(span));
 
         Ok(ListStream::new(iter, call.head, engine_state.signals().clone()).into())
     }
@@ -11,11 +11,13 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
 use nu_protocol::{record, CustomValue, ShellError, Span, Value};
 use serde::Serialize;
-use zenoh::{key_expr::OwnedKeyExpr, liveliness::LivelinessToken};
+use zenoh::key_expr::OwnedKeyExpr;
+
+use crate::declarations::Declaration;
 
 pub(crate) mod decl;
 pub(crate) mod get;
@@ -23,7 +25,7 @@ pub(crate) mod undecl;
 
 #[derive(Debug, Clone)]
 struct LivelinessTokenValue {
-    handle: Arc<Mutex<Option<LivelinessToken>>>,
+    declaration: Arc<Declaration>,
     keyexpr: OwnedKeyExpr,
 }
 
@@ -14,11 +14,13 @@
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+    LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
 };
-use zenoh::Wait;
+use zenoh::{liveliness::LivelinessToken, Wait};
 
-use crate::{cmd::liveliness::LivelinessTokenValue, signature_ext::SignatureExt, State};
+use crate::{
+    cmd::liveliness::LivelinessTokenValue, completion, signature_ext::SignatureExt, State,
+};
 
 #[derive(Clone)]
 pub(crate) struct Undecl {
@@ -49,6 +51,8 @@ impl Command for Undecl {
         "Zenoh liveliness token undeclaration"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -57,18 +61,28 @@ impl Command for Undecl {
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let token_value = call.req::<Value>(engine_state, stack, 0)?;
-        let mut custom_value = token_value.into_custom_value()?;
+        let custom_value = token_value.into_custom_value()?;
 
-        let mut token_lock = custom_value
-            .as_mut_any()
-            .downcast_mut::<LivelinessTokenValue>()
+        let declaration = &custom_value
+            .as_any()
+            .downcast_ref::<LivelinessTokenValue>()
             .unwrap()
-            .handle
-            .lock()
-            .unwrap();
+            .declaration;
 
-        if let Some(token) = token_lock.take() {
-            token.undeclare().wait().unwrap();
+        if let Some(token) = declaration.take() {
+            let token = token.downcast::<LivelinessToken>().map_err(|_| {
+                ShellError::NushellFailedSpanned {
+                    msg: "liveliness token declaration holds another entity".to_string(),
+                    label: "not a liveliness token".to_string(),
+                    span: call.head,
+                }
+            })?;
+            token.undeclare().wait().map_err(|e| {
+                LabeledError::new("Liveliness token undeclaration failed").with_label(
+                    format!("Zenoh Liveliness token undeclaration failed: {e}"),
+                    call.head,
+                )
+            })?;
         }
 
         Ok(PipelineData::Empty)
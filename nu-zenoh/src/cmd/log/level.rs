@@ -0,0 +1,77 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::{signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Level {
+    state: State,
+}
+
+impl Level {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Level {
+    fn name(&self) -> &str {
+        "zenoh log level"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::String)
+            .optional(
+                "directive",
+                SyntaxShape::String,
+                "Filter directive (e.g. 'zenoh=debug' or 'zenoh::net=trace,info')",
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Get or set the log filter"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Uses the same syntax as the ZENOH_NU_LOG environment variable, which falls back to \
+        RUST_LOG and then to 'zenoh=info'. Returns the filter in effect."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        if let Some(directive) = call.opt::<String>(engine_state, stack, 0)? {
+            self.state.logging.set_filter(&directive).map_err(|e| {
+                LabeledError::new("Invalid log filter")
+                    .with_label(format!("Could not parse filter directive: {e}"), call.head)
+            })?;
+        }
+
+        Ok(PipelineData::Value(
+            Value::string(self.state.logging.filter(), call.head),
+            None,
+        ))
+    }
+}
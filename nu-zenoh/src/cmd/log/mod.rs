@@ -0,0 +1,131 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::str::FromStr;
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, ListStream, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+use tracing::Level;
+
+use crate::{
+    completion, interruptible_channel::InterruptibleChannel, logging::LogRecord,
+    signature_ext::SignatureExt, State,
+};
+
+pub(crate) mod level;
+pub(crate) mod rotate;
+
+#[derive(Clone)]
+pub(crate) struct Log {
+    state: State,
+}
+
+impl Log {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Log {
+    fn name(&self) -> &str {
+        "zenoh log"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .named(
+                "session",
+                SyntaxShape::String,
+                "Only show records of this session (defaults to all sessions)",
+                Some('s'),
+            )
+            .named(
+                "level",
+                SyntaxShape::String,
+                "Maximum level (either 'error', 'warn', 'info', 'debug' or 'trace')",
+                Some('l'),
+            )
+            .switch(
+                "follow",
+                "Keep streaming records as they are emitted",
+                Some('f'),
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Recent log records"
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let zid = match call.get_flag::<String>(engine_state, stack, "session")? {
+            Some(name) => Some(
+                self.state
+                    .with_session(&name, |sess| sess.zid().to_string())?,
+            ),
+            None => None,
+        };
+
+        let level = call
+            .get_flag::<String>(engine_state, stack, "level")?
+            .map(|level| {
+                Level::from_str(&level).map_err(|_| {
+                    LabeledError::new("Invalid level")
+                        .with_label("Must be 'error', 'warn', 'info', 'debug' or 'trace'", span)
+                })
+            })
+            .transpose()?;
+
+        let filter = move |record: &LogRecord| {
+            level.is_none_or(|level| record.level <= level)
+                && zid.as_deref().is_none_or(|zid| record.matches_zid(zid))
+        };
+
+        let signals = engine_state.signals().clone();
+
+        if call.has_flag(engine_state, stack, "follow")? {
+            let (records, rx) = self.state.logs.follow();
+            let iter = records
+                .into_iter()
+                .chain(InterruptibleChannel::new(rx, signals.clone()))
+                .filter(filter)
+                .map(move |record| record.into_value(span));
+
+            Ok(ListStream::new(iter, span, signals).into())
+        } else {
+            let iter = self
+                .state
+                .logs
+                .records()
+                .into_iter()
+                .filter(filter)
+                .map(move |record| record.into_value(span));
+
+            Ok(ListStream::new(iter, span, signals).into())
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, PipelineData, ShellError, Signature, Type, Value,
+};
+
+use crate::{signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Rotate {
+    state: State,
+}
+
+impl Rotate {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Rotate {
+    fn name(&self) -> &str {
+        "zenoh log rotate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::String)
+    }
+
+    fn description(&self) -> &str {
+        "Start a new log file and return the path of the previous one"
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let old_path = self.state.logging.rotate().map_err(|e| {
+            LabeledError::new("Failed to rotate log file")
+                .with_label(format!("Could not create new log file: {e}"), call.head)
+        })?;
+
+        Ok(PipelineData::Value(
+            Value::string(old_path.to_string_lossy(), call.head),
+            None,
+        ))
+    }
+}
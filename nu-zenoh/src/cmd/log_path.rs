@@ -11,52 +11,21 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use std::{fs::File, path::PathBuf};
-
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
     PipelineData, ShellError, Signature, Type, Value,
 };
-use tracing_subscriber::{
-    fmt::format::FmtSpan, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter,
-};
 
-use crate::{signature_ext::SignatureExt, State};
+use crate::{completion, signature_ext::SignatureExt, State};
 
 #[derive(Clone)]
 pub(crate) struct LogPath {
-    _state: State,
-    log_path: PathBuf,
+    state: State,
 }
 
 impl LogPath {
     pub(crate) fn new(state: State) -> Self {
-        let log_path = tempfile::tempdir()
-            .unwrap()
-            .keep()
-            .join("zenoh.log")
-            .to_path_buf();
-
-        const ENV_FILTER_NAME: &str = "ZENOH_NU_LOG";
-        const ENV_FILTER_DEFAULT: &str = "zenoh=trace";
-
-        let env_filter = EnvFilter::try_from_env(ENV_FILTER_NAME)
-            .unwrap_or_else(|_| EnvFilter::new(ENV_FILTER_DEFAULT));
-
-        let fmt = tracing_subscriber::fmt::layer()
-            .with_writer(File::create(&log_path).unwrap())
-            .with_ansi(false)
-            .with_span_events(FmtSpan::ACTIVE);
-
-        tracing_subscriber::registry()
-            .with(env_filter)
-            .with(fmt)
-            .init();
-
-        Self {
-            _state: state,
-            log_path,
-        }
+        Self { state }
     }
 }
 
@@ -76,6 +45,8 @@ impl Command for LogPath {
         "Get the log file path for the specified session"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         _engine_state: &EngineState,
@@ -83,10 +54,10 @@ impl Command for LogPath {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        // FIXME(fuzzypixelz): it's not possible to filter tracing events by
-        // session as the zenoh crate doesn't (yet) provide that information.
+        // NOTE: the log file is shared by all sessions, see 'zenoh log --session'
+        // for per-session records.
         Ok(PipelineData::Value(
-            Value::string(self.log_path.to_string_lossy(), call.head),
+            Value::string(self.state.logging.path().to_string_lossy(), call.head),
             None,
         ))
     }
@@ -11,17 +11,25 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+pub(crate) mod bench;
 pub(crate) mod config;
 pub(crate) mod delete;
 pub(crate) mod get;
 pub(crate) mod info;
 pub(crate) mod keyexpr;
 pub(crate) mod liveliness;
+pub(crate) mod log;
 pub(crate) mod log_path;
+pub(crate) mod ping;
+pub(crate) mod pong;
 pub(crate) mod put;
 pub(crate) mod reply;
 pub(crate) mod runtime;
+pub(crate) mod sandbox;
 pub(crate) mod scout;
 pub(crate) mod session;
 pub(crate) mod sub;
+pub(crate) mod watch;
+pub(crate) mod with_session;
+pub(crate) mod workspace;
 pub(crate) mod zid;
@@ -0,0 +1,289 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::time::{Duration, Instant};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, ListStream, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+use zenoh::{
+    pubsub::{Publisher, Subscriber},
+    qos::CongestionControl,
+    Wait,
+};
+
+use crate::{call_ext2::CallExt2, completion, signature_ext::SignatureExt, State};
+
+/// Suffix of the key expression on which pings are published.
+pub(crate) const PING_SUFFIX: &str = "ping";
+
+/// Suffix of the key expression on which pongs are published.
+pub(crate) const PONG_SUFFIX: &str = "pong";
+
+#[derive(Clone)]
+pub(crate) struct Ping {
+    state: State,
+}
+
+impl Ping {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Ping {
+    fn name(&self) -> &str {
+        "zenoh ping"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .required(
+                "keyexpr",
+                SyntaxShape::String,
+                "Key expression prefix (shared with 'zenoh pong')",
+            )
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "Number of round-trips (defaults to 100)",
+                Some('n'),
+            )
+            .size()
+            .named(
+                "interval",
+                SyntaxShape::Duration,
+                "Delay between round-trips (defaults to none)",
+                Some('i'),
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "Maximum time to wait for each pong (defaults to 1sec)",
+                None,
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Measure round-trip latency against 'zenoh pong'"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Publishes on '<keyexpr>/ping' and waits for the echo on '<keyexpr>/pong'. \
+        Streams one record per round-trip and ends with a summary record."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        const PONG_CHANNEL_SIZE: usize = 256;
+        const DEFAULT_COUNT: usize = 100;
+        const DEFAULT_SIZE: usize = 64;
+        const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+        let span = call.head;
+
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let count = match call.get_flag::<i64>(engine_state, stack, "count")? {
+            Some(count) => usize::try_from(count).map_err(|_| {
+                LabeledError::new("Invalid count").with_label("Count must be non-negative", span)
+            })?,
+            None => DEFAULT_COUNT,
+        };
+        // The payload carries the sequence number to discard late pongs
+        let size = call
+            .size(engine_state, stack)?
+            .unwrap_or(DEFAULT_SIZE)
+            .max(size_of::<u64>());
+        let interval = call.interval(engine_state, stack)?.unwrap_or_default();
+        let timeout = call
+            .timeout(engine_state, stack)?
+            .unwrap_or(DEFAULT_TIMEOUT);
+
+        let (tx, rx) = flume::bounded(PONG_CHANNEL_SIZE);
+
+        let (publisher, subscriber) = self
+            .state
+            .with_session(&call.session(engine_state, stack)?, |sess| {
+                let subscriber = sess
+                    .declare_subscriber(format!("{keyexpr}/{PONG_SUFFIX}"))
+                    .callback(move |sample| {
+                        let _ =
+                            tx.try_send((Instant::now(), sample.payload().to_bytes().into_owned()));
+                    })
+                    .wait()?;
+                let publisher = sess
+                    .declare_publisher(format!("{keyexpr}/{PING_SUFFIX}"))
+                    .congestion_control(CongestionControl::Block)
+                    .express(true)
+                    .wait()?;
+                Ok::<_, zenoh::Error>((publisher, subscriber))
+            })?
+            .map_err(|e| {
+                LabeledError::new("Ping declaration failed")
+                    .with_label(format!("Zenoh ping failed: {e}"), span)
+            })?;
+
+        let iter = PingIter {
+            publisher,
+            _subscriber: subscriber,
+            rx,
+            size,
+            count,
+            interval,
+            timeout,
+            seq: 0,
+            rtts: Vec::with_capacity(count),
+            done: false,
+            span,
+        };
+
+        Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+    }
+}
+
+struct PingIter {
+    publisher: Publisher<'static>,
+    _subscriber: Subscriber<()>,
+    rx: flume::Receiver<(Instant, Vec<u8>)>,
+    size: usize,
+    count: usize,
+    interval: Duration,
+    timeout: Duration,
+    seq: u64,
+    rtts: Vec<Duration>,
+    done: bool,
+    span: Span,
+}
+
+impl PingIter {
+    /// Sends a ping and waits for the matching pong, returning the round-trip time.
+    fn round_trip(&mut self) -> Result<Option<Duration>, zenoh::Error> {
+        let mut payload = vec![0u8; self.size];
+        payload[..size_of::<u64>()].copy_from_slice(&self.seq.to_le_bytes());
+
+        // Discard pongs of previous (timed out) pings
+        let _ = self.rx.drain();
+
+        let start = Instant::now();
+        self.publisher.put(payload).wait()?;
+
+        let deadline = start + self.timeout;
+        loop {
+            match self.rx.recv_deadline(deadline) {
+                Ok((end, pong)) if pong.starts_with(&self.seq.to_le_bytes()) => {
+                    return Ok(Some(end - start));
+                }
+                Ok(_) => continue,
+                Err(_) => return Ok(None),
+            }
+        }
+    }
+
+    fn summary(&self) -> Value {
+        let span = self.span;
+
+        let mut rtts = self.rtts.clone();
+        rtts.sort();
+
+        let stat = |rtt: Option<Duration>| {
+            rtt.map(|rtt| Value::duration(rtt.as_nanos() as i64, span))
+                .unwrap_or_default()
+        };
+        let avg = (!rtts.is_empty()).then(|| rtts.iter().sum::<Duration>() / rtts.len() as u32);
+
+        record!(
+            "summary" => true.into_value(span),
+            "sent" => (self.seq as i64).into_value(span),
+            "received" => (rtts.len() as i64).into_value(span),
+            "lost" => ((self.seq as usize - rtts.len()) as i64).into_value(span),
+            "min" => stat(rtts.first().copied()),
+            "avg" => stat(avg),
+            "p50" => stat(percentile(&rtts, 50)),
+            "p99" => stat(percentile(&rtts, 99)),
+            "max" => stat(rtts.last().copied()),
+        )
+        .into_value(span)
+    }
+}
+
+impl Iterator for PingIter {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let span = self.span;
+
+        if self.done {
+            return None;
+        }
+
+        if self.seq as usize == self.count {
+            self.done = true;
+            return Some(self.summary());
+        }
+
+        if self.seq > 0 && !self.interval.is_zero() {
+            std::thread::sleep(self.interval);
+        }
+
+        let rtt = match self.round_trip() {
+            Ok(rtt) => rtt,
+            Err(e) => {
+                self.done = true;
+                return Some(Value::error(
+                    LabeledError::new("Ping failed")
+                        .with_label(format!("Zenoh put failed: {e}"), span)
+                        .into(),
+                    span,
+                ));
+            }
+        };
+
+        let seq = self.seq;
+        self.seq += 1;
+        self.rtts.extend(rtt);
+
+        Some(
+            record!(
+                "seq" => (seq as i64).into_value(span),
+                "size" => (self.size as i64).into_value(span),
+                "rtt" => rtt
+                    .map(|rtt| Value::duration(rtt.as_nanos() as i64, span))
+                    .unwrap_or_default(),
+                "lost" => rtt.is_none().into_value(span),
+            )
+            .into_value(span),
+        )
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], p: usize) -> Option<Duration> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
@@ -0,0 +1,119 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type,
+};
+use zenoh::{qos::CongestionControl, Session, Wait};
+
+use crate::{
+    call_ext2::CallExt2,
+    cmd::ping::{PING_SUFFIX, PONG_SUFFIX},
+    completion,
+    interruptible_channel::InterruptibleChannel,
+    signature_ext::SignatureExt,
+    State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Pong {
+    state: State,
+}
+
+impl Pong {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Pong {
+    fn name(&self) -> &str {
+        "zenoh pong"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::Nothing)
+            .required(
+                "keyexpr",
+                SyntaxShape::String,
+                "Key expression prefix (shared with 'zenoh ping')",
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Echo pings sent by 'zenoh ping'"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Republishes every sample received on '<keyexpr>/ping' to '<keyexpr>/pong' \
+        until interrupted or until the session is closed. Use 'job spawn' to run it in the background."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        const PING_CHANNEL_SIZE: usize = 1;
+
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+
+        // The channel only serves to block until interrupted or until the subscriber is dropped
+        let (tx, rx) = flume::bounded(PING_CHANNEL_SIZE);
+
+        let session_name = call.session(engine_state, stack)?;
+        let session = self.state.with_session(&session_name, Session::clone)?;
+
+        let subscriber = self
+            .state
+            .with_session(&session_name, |sess| {
+                let publisher = sess
+                    .declare_publisher(format!("{keyexpr}/{PONG_SUFFIX}"))
+                    .congestion_control(CongestionControl::Block)
+                    .express(true)
+                    .wait()?;
+                sess.declare_subscriber(format!("{keyexpr}/{PING_SUFFIX}"))
+                    .callback(move |sample| {
+                        let _ = publisher.put(sample.payload().clone()).wait();
+                        let _ = tx.try_send(());
+                    })
+                    .wait()
+            })?
+            .map_err(|e| {
+                LabeledError::new("Pong declaration failed")
+                    .with_label(format!("Zenoh pong failed: {e}"), call.head)
+            })?;
+
+        let (pings, closed) =
+            InterruptibleChannel::with_data(rx, engine_state.signals().clone(), subscriber)
+                .watch_session(session);
+        for () in pings {}
+
+        if closed.is_closed() {
+            return Err(LabeledError::new("Session closed")
+                .with_label("The session was closed while answering pings", call.head)
+                .into());
+        }
+
+        Ok(PipelineData::empty())
+    }
+}
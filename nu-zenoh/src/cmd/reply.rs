@@ -11,16 +11,18 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+use std::sync::Arc;
+
 use nu_engine::{CallExt, ClosureEval};
 use nu_protocol::{
     engine::{Call, Closure, Command, EngineState, Stack},
     ListStream, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
 };
-use zenoh::Wait;
+use zenoh::{Session, Wait};
 
 use crate::{
-    call_ext2::CallExt2, conv, interruptible_channel::InterruptibleChannel,
-    signature_ext::SignatureExt, State,
+    call_ext2::CallExt2, completion, conv, flow_control,
+    interruptible_channel::InterruptibleChannel, signature_ext::SignatureExt, State,
 };
 
 #[derive(Clone)]
@@ -52,12 +54,15 @@ impl Command for Reply {
             )
             .complete()
             .allowed_origin()
+            .flow_control()
     }
 
     fn description(&self) -> &str {
         "Zenoh Queryable declaration"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -67,40 +72,59 @@ impl Command for Reply {
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
 
-        const REPLY_CHANNEL_SIZE: usize = 256;
-        let (tx, rx) = flume::bounded(REPLY_CHANNEL_SIZE);
+        let (tx, rx, dropped) = flow_control::channel(call.flow_control(engine_state, stack)?);
 
         let handler = call.req::<Closure>(engine_state, stack, 1)?;
         let mut closure = ClosureEval::new(engine_state, stack, handler);
         let signals = engine_state.signals().clone();
         let engine = engine_state.clone();
 
+        let session_name = call.session(engine_state, stack)?;
+        let session = self.state.with_session(&session_name, Session::clone)?;
+
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let origin = call.allowed_origin(engine_state, stack)?;
+        let complete = call.complete(engine_state, stack)?;
+        let tx = Arc::new(tx);
+
         let queryable = self
             .state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
-                let mut queryable = sess
-                    .declare_queryable(call.req::<String>(engine_state, stack, 0)?)
-                    .callback(move |query| {
-                        let _ = tx.send(query);
-                    });
-
-                if let Some(origin) = call.allowed_origin(engine_state, stack)? {
-                    queryable = queryable.allowed_origin(origin);
-                }
+            .declarations
+            .declare(
+                &session_name,
+                &session,
+                "queryable",
+                keyexpr.clone(),
+                move |sess| {
+                    let tx = tx.clone();
+                    let mut queryable =
+                        sess.declare_queryable(keyexpr.clone())
+                            .callback(move |query| {
+                                tx.send(query);
+                            });
+
+                    if let Some(origin) = origin {
+                        queryable = queryable.allowed_origin(origin);
+                    }
 
-                if let Some(complete) = call.complete(engine_state, stack)? {
-                    queryable = queryable.complete(complete);
-                }
+                    if let Some(complete) = complete {
+                        queryable = queryable.complete(complete);
+                    }
 
-                queryable.wait()
-            })?
+                    queryable.wait()
+                },
+            )
             .map_err(|e| {
                 nu_protocol::LabeledError::new("Queryable declaration failed")
                     .with_label(format!("Zenoh queryable failed: {e}"), span)
             })?;
 
-        let iter = InterruptibleChannel::with_data(rx, engine.signals().clone(), queryable).map(
-            move |query| {
+        let (queries, closed) =
+            InterruptibleChannel::with_data(rx, engine.signals().clone(), queryable.clone())
+                .watch_declaration(queryable);
+
+        let iter = queries
+            .map(move |query| {
                 let value = conv::query_to_record_value(&query, span);
 
                 match closure.run_with_value(value) {
@@ -123,8 +147,9 @@ impl Command for Reply {
                         Err(err) => Value::error(ShellError::from(err), span),
                     },
                 }
-            },
-        );
+            })
+            .chain(dropped.report(span))
+            .chain(closed.report(span));
 
         Ok(ListStream::new(iter, span, signals).into())
     }
@@ -3,9 +3,8 @@ use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
     PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
 };
-use zenoh::Wait;
 
-use crate::{signature_ext::SignatureExt, State};
+use crate::{cmd::runtime::close_runtime, completion, signature_ext::SignatureExt, State};
 
 #[derive(Clone)]
 pub(crate) struct Close {
@@ -25,7 +24,12 @@ impl Command for Close {
 
     fn signature(&self) -> Signature {
         Signature::build(self.name())
-            .required("runtime", SyntaxShape::Filepath, "Runtime name")
+            .required("runtime", SyntaxShape::String, "Runtime name")
+            .switch(
+                "force",
+                "Close the sessions attached to the runtime as well",
+                Some('f'),
+            )
             .zenoh_category()
             .input_output_type(Type::Nothing, Type::Nothing)
     }
@@ -34,6 +38,13 @@ impl Command for Close {
         "Close a runtime"
     }
 
+    fn extra_description(&self) -> &str {
+        "Fails if sessions opened with 'zenoh session open --runtime' are still attached to the \
+        runtime, unless --force is given."
+    }
+
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -42,13 +53,8 @@ impl Command for Close {
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let runtime_name = call.req::<String>(engine_state, stack, 0)?;
-        let mut runtimes = self.state.runtimes.write().unwrap();
-        if let Some(runtime) = runtimes.remove(&runtime_name) {
-            runtime.close().wait().map_err(|e| {
-                nu_protocol::LabeledError::new("Failed to close Zenoh session '{session_name}'")
-                    .with_label(format!("Could not close Zenoh session: {e}"), call.head)
-            })?
-        }
+        let force = call.has_flag(engine_state, stack, "force")?;
+        close_runtime(&self.state, &runtime_name, force, call.head)?;
 
         Ok(PipelineData::Value(Value::nothing(call.head), None))
     }
@@ -0,0 +1,106 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+use zenoh::internal::runtime::Runtime;
+
+use crate::{completion, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Info {
+    state: State,
+}
+
+impl Info {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Info {
+    fn name(&self) -> &str {
+        "zenoh runtime info"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("runtime", SyntaxShape::String, "Runtime name")
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::record())
+    }
+
+    fn description(&self) -> &str {
+        "Show information about a runtime"
+    }
+
+    fn extra_description(&self) -> &str {
+        "The 'locators' are those the runtime is listening on, and the 'sessions' are the names of \
+        the sessions opened on it with 'zenoh session open --runtime'."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let runtime_name = call.req::<String>(engine_state, stack, 0)?;
+        let runtimes = self.state.runtimes.read().unwrap();
+        let runtime = runtimes.get(&runtime_name).ok_or_else(|| {
+            let mut names = runtimes.keys().map(String::as_str).collect::<Vec<_>>();
+            names.sort();
+            LabeledError::new(format!(
+                "runtime '{runtime_name}' not found (open runtimes: {})",
+                names.join(", ")
+            ))
+        })?;
+
+        Ok(PipelineData::Value(
+            runtime_record(&self.state, &runtime_name, runtime, call.head),
+            None,
+        ))
+    }
+}
+
+pub(super) fn runtime_record(state: &State, name: &str, runtime: &Runtime, span: Span) -> Value {
+    let locators = runtime
+        .get_locators()
+        .into_iter()
+        .map(|locator| Value::string(locator.to_string(), span))
+        .collect::<Vec<_>>();
+    let sessions = state
+        .attached_sessions(name)
+        .into_iter()
+        .map(|session| Value::string(session, span))
+        .collect::<Vec<_>>();
+
+    Value::record(
+        record!(
+            "name" => Value::string(name, span),
+            "zid" => runtime.zid().to_string().into_value(span),
+            "whatami" => runtime.whatami().to_string().into_value(span),
+            "locators" => Value::list(locators, span),
+            "sessions" => Value::list(sessions, span),
+            "closed" => runtime.is_closed().into_value(span),
+        ),
+        span,
+    )
+}
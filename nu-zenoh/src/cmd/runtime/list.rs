@@ -13,10 +13,10 @@
 //
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    record, IntoValue, PipelineData, ShellError, Signature, Type, Value,
+    PipelineData, ShellError, Signature, Type, Value,
 };
 
-use crate::{signature_ext::SignatureExt, State};
+use crate::{cmd::runtime::info::runtime_record, signature_ext::SignatureExt, State};
 
 #[derive(Clone)]
 pub(crate) struct List {
@@ -37,13 +37,17 @@ impl Command for List {
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .zenoh_category()
-            .input_output_type(Type::Nothing, Type::record())
+            .input_output_type(Type::Nothing, Type::table())
     }
 
     fn description(&self) -> &str {
         "List opened runtimes"
     }
 
+    fn extra_description(&self) -> &str {
+        "See 'zenoh runtime info' for the meaning of each column."
+    }
+
     fn run(
         &self,
         _engine_state: &EngineState,
@@ -53,15 +57,13 @@ impl Command for List {
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
         let runtimes = self.state.runtimes.read().unwrap();
-        let runtime_list = runtimes
-            .iter()
-            .map(|(name, sess)| {
-                record!(
-                    "name" => name.clone().into_value(span),
-                    "zid" => sess.zid().to_string().into_value(span)
-                )
-                .into_value(span)
-            })
+
+        let mut names = runtimes.keys().collect::<Vec<_>>();
+        names.sort();
+
+        let runtime_list = names
+            .into_iter()
+            .map(|name| runtime_record(&self.state, name, &runtimes[name], span))
             .collect::<Vec<_>>();
 
         Ok(PipelineData::Value(Value::list(runtime_list, span), None))
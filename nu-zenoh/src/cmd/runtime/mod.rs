@@ -1,3 +1,68 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{LabeledError, Span};
+use zenoh::Wait;
+
+use crate::State;
+
 pub(crate) mod close;
+pub(crate) mod info;
 pub(crate) mod list;
 pub(crate) mod open;
+
+/// Closes the runtime named `name`, if any.
+///
+/// Fails when sessions are still attached to it, unless `cascade` is set, in which case they
+/// are closed first.
+pub(crate) fn close_runtime(
+    state: &State,
+    name: &str,
+    cascade: bool,
+    span: Span,
+) -> Result<(), LabeledError> {
+    if !state.runtimes.read().unwrap().contains_key(name) {
+        return Ok(());
+    }
+
+    let attached = state.attached_sessions(name);
+    if !attached.is_empty() && !cascade {
+        return Err(LabeledError::new(format!(
+            "Zenoh runtime '{name}' still has attached sessions"
+        ))
+        .with_label(format!("Attached sessions: {}", attached.join(", ")), span)
+        .with_help("Close them first, or use 'zenoh runtime close --force' to close them too"));
+    }
+
+    for session_name in attached {
+        let session = state.sessions.write().unwrap().remove(&session_name);
+        if let Some(session) = session {
+            state.session_closed(&session_name);
+            session.close().wait().map_err(|e| {
+                LabeledError::new(format!("Failed to close Zenoh session '{session_name}'"))
+                    .with_label(format!("Could not close Zenoh session: {e}"), span)
+            })?;
+        }
+    }
+
+    // Sessions are locked before runtimes, so the runtimes can't stay locked until now
+    let runtime = state.runtimes.write().unwrap().remove(name);
+    if let Some(runtime) = runtime {
+        runtime.close().wait().map_err(|e| {
+            LabeledError::new(format!("Failed to close Zenoh runtime '{name}'"))
+                .with_label(format!("Could not close Zenoh runtime: {e}"), span)
+        })?;
+    }
+
+    Ok(())
+}
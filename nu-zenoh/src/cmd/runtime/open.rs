@@ -16,14 +16,11 @@ use std::path::PathBuf;
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
-};
-use zenoh::{
-    internal::runtime::{RuntimeBuilder, ZRuntime},
-    Wait,
+    LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use zenoh::internal::runtime::{Runtime, RuntimeBuilder, ZRuntime};
 
-use crate::{conv, signature_ext::SignatureExt, State};
+use crate::{cmd::runtime::close_runtime, completion, conv, signature_ext::SignatureExt, State};
 
 #[derive(Clone)]
 pub(crate) struct Open {
@@ -45,7 +42,7 @@ impl Command for Open {
         Signature::build(self.name())
             .required(
                 "runtime",
-                SyntaxShape::Filepath,
+                SyntaxShape::String,
                 "Runtime name",
             )
             .zenoh_category()
@@ -67,6 +64,13 @@ impl Command for Open {
         "Create a runtime"
     }
 
+    fn extra_description(&self) -> &str {
+        "Several sessions can be opened on the same runtime with 'zenoh session open --runtime', \
+        each under its own name. Re-creating a runtime fails while sessions are attached to it."
+    }
+
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -76,7 +80,7 @@ impl Command for Open {
     ) -> Result<PipelineData, ShellError> {
         // FIXME(fuzzypixelz): refactor this (see 'zenoh session open')
 
-        let file_path = call.get_flag::<PathBuf>(engine_state, stack, "file")?;
+        let file_path = call.get_flag::<PathBuf>(engine_state, stack, "config-file")?;
         let config_record = call.opt::<Value>(engine_state, stack, 1)?;
 
         let config = match (file_path.as_ref(), config_record.as_ref()) {
@@ -108,10 +112,11 @@ impl Command for Open {
             (Some(_), Some(_)) => {
                 return Err(ShellError::GenericError {
                     error: "Conflicting arguments".to_string(),
-                    msg: "Cannot specify both --file and config record".to_string(),
+                    msg: "Cannot specify both --config-file and config record".to_string(),
                     span: Some(call.head),
                     help: Some(
-                        "Use either --file <path> or provide a config record, not both".to_string(),
+                        "Use either --config-file <path> or provide a config record, not both"
+                            .to_string(),
                     ),
                     inner: vec![],
                 });
@@ -120,30 +125,34 @@ impl Command for Open {
         };
 
         let runtime_name = call.req::<String>(engine_state, stack, 0)?;
-        let mut runtimes = self.state.runtimes.write().unwrap();
-        if let Some(runtime) = runtimes.remove(&runtime_name) {
-            runtime.close().wait().map_err(|e| {
-                nu_protocol::LabeledError::new("Failed to re-create Zenoh runtime '{runtime_name}'")
-                    .with_label(format!("Could not close Zenoh runtime: {e}"), call.head)
-            })?
-        }
-
-        let mut new_runtime = ZRuntime::Application
-            .block_on(RuntimeBuilder::new(config).build())
-            .map_err(|e| {
-                nu_protocol::LabeledError::new("Failed to open Zenoh runtime")
-                    .with_label(format!("Could not open Zenoh runtime: {e}"), call.head)
-            })?;
-
-        ZRuntime::Application
-            .block_on(new_runtime.start())
-            .map_err(|e| {
-                nu_protocol::LabeledError::new("Failed to start Zenoh runtime")
-                    .with_label(format!("Could not start Zenoh runtime: {e}"), call.head)
-            })?;
-
-        runtimes.insert(runtime_name, new_runtime);
+        close_runtime(&self.state, &runtime_name, false, call.head)?;
+
+        let runtime = start_runtime(config, call.head)?;
+        self.state
+            .runtimes
+            .write()
+            .unwrap()
+            .insert(runtime_name, runtime);
 
         Ok(PipelineData::Value(Value::nothing(call.head), None))
     }
 }
+
+/// Builds and starts a runtime.
+pub(crate) fn start_runtime(config: zenoh::Config, span: Span) -> Result<Runtime, LabeledError> {
+    let mut runtime = ZRuntime::Application
+        .block_on(RuntimeBuilder::new(config).build())
+        .map_err(|e| {
+            LabeledError::new("Failed to open Zenoh runtime")
+                .with_label(format!("Could not open Zenoh runtime: {e}"), span)
+        })?;
+
+    ZRuntime::Application
+        .block_on(runtime.start())
+        .map_err(|e| {
+            LabeledError::new("Failed to start Zenoh runtime")
+                .with_label(format!("Could not start Zenoh runtime: {e}"), span)
+        })?;
+
+    Ok(runtime)
+}
@@ -0,0 +1,77 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    IntoValue, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::{
+    cmd::sandbox::{close_nodes, DEFAULT_SANDBOX_NAME},
+    signature_ext::SignatureExt,
+    State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Down {
+    state: State,
+}
+
+impl Down {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Down {
+    fn name(&self) -> &str {
+        "zenoh sandbox down"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::String))
+            .named(
+                "name",
+                SyntaxShape::String,
+                "Sandbox name (defaults to 'sandbox')",
+                Some('n'),
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Close all sessions and runtimes of a sandbox"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let sandbox = call
+            .get_flag::<String>(engine_state, stack, "name")?
+            .unwrap_or(DEFAULT_SANDBOX_NAME.to_string());
+
+        let names = close_nodes(&self.state, &sandbox, span)?
+            .into_iter()
+            .map(|name| name.into_value(span))
+            .collect::<Vec<_>>();
+
+        Ok(PipelineData::Value(Value::list(names, span), None))
+    }
+}
@@ -0,0 +1,76 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_protocol::{LabeledError, Span};
+use zenoh::Wait;
+
+use crate::State;
+
+pub(crate) mod down;
+pub(crate) mod up;
+
+/// Default sandbox name.
+const DEFAULT_SANDBOX_NAME: &str = "sandbox";
+
+/// Returns the prefix shared by the names of all sessions (and runtimes) of a sandbox.
+///
+/// Sandbox nodes are named `<sandbox>/<mode>-<index>` (e.g. `sandbox/router-0`).
+fn node_prefix(sandbox: &str) -> String {
+    format!("{sandbox}/")
+}
+
+/// Closes and forgets all sessions and runtimes of a sandbox, returning their names.
+fn close_nodes(state: &State, sandbox: &str, span: Span) -> Result<Vec<String>, LabeledError> {
+    let prefix = node_prefix(sandbox);
+
+    let mut names = Vec::new();
+
+    let mut sessions = state.sessions.write().unwrap();
+    let session_names = sessions
+        .keys()
+        .filter(|name| name.starts_with(&prefix))
+        .cloned()
+        .collect::<Vec<_>>();
+    for name in session_names {
+        if let Some(sess) = sessions.remove(&name) {
+            state.session_closed(&name);
+            sess.close().wait().map_err(|e| {
+                LabeledError::new(format!("Failed to close Zenoh session '{name}'"))
+                    .with_label(format!("Could not close Zenoh session: {e}"), span)
+            })?;
+        }
+        names.push(name);
+    }
+
+    let mut runtimes = state.runtimes.write().unwrap();
+    let runtime_names = runtimes
+        .keys()
+        .filter(|name| name.starts_with(&prefix))
+        .cloned()
+        .collect::<Vec<_>>();
+    for name in runtime_names {
+        if let Some(runtime) = runtimes.remove(&name) {
+            runtime.close().wait().map_err(|e| {
+                LabeledError::new(format!("Failed to close Zenoh runtime '{name}'"))
+                    .with_label(format!("Could not close Zenoh runtime: {e}"), span)
+            })?;
+        }
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    state.sandbox_dirs.lock().unwrap().remove(sandbox);
+
+    Ok(names)
+}
@@ -0,0 +1,309 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::path::PathBuf;
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+use zenoh::{config::WhatAmI, session, Session, Wait};
+
+use crate::{
+    cmd::{
+        runtime::open::start_runtime,
+        sandbox::{close_nodes, node_prefix, DEFAULT_SANDBOX_NAME},
+    },
+    session_meta::SessionSource,
+    signature_ext::SignatureExt,
+    State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Up {
+    state: State,
+}
+
+impl Up {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Up {
+    fn name(&self) -> &str {
+        "zenoh sandbox up"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .named(
+                "name",
+                SyntaxShape::String,
+                "Sandbox name (defaults to 'sandbox')",
+                Some('n'),
+            )
+            .named("routers", SyntaxShape::Int, "Number of routers", Some('r'))
+            .named("peers", SyntaxShape::Int, "Number of peers", Some('p'))
+            .named("clients", SyntaxShape::Int, "Number of clients", Some('c'))
+            .named(
+                "transport",
+                SyntaxShape::String,
+                "Transport used between nodes (either 'tcp' or 'unixsock-stream', defaults to 'tcp')",
+                Some('t'),
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Open a local topology of routers, peers and clients"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Each node is a session named '<sandbox>/<mode>-<index>' (e.g. 'sandbox/router-0'). \
+        Routers are fully meshed, peers connect to all routers (or to each other if there are \
+        no routers) and clients connect to all routers (or peers). Multicast scouting is disabled. \
+        TCP nodes listen on a port picked by the system, unix sockets are created in a temporary \
+        directory removed by 'zenoh sandbox down'. With internal options enabled, the runtime of \
+        each node is listed under the same name. An existing sandbox of the same name is closed \
+        first."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let sandbox = call
+            .get_flag::<String>(engine_state, stack, "name")?
+            .unwrap_or(DEFAULT_SANDBOX_NAME.to_string());
+        let routers = count(call, engine_state, stack, "routers")?;
+        let peers = count(call, engine_state, stack, "peers")?;
+        let clients = count(call, engine_state, stack, "clients")?;
+        let unixsock_dir = match call
+            .get_flag::<String>(engine_state, stack, "transport")?
+            .as_deref()
+        {
+            None | Some("tcp") => None,
+            Some("unixsock-stream") => Some(tempfile::tempdir().map_err(|e| {
+                LabeledError::new("Failed to create sandbox directory")
+                    .with_label(e.to_string(), span)
+            })?),
+            Some(_) => {
+                return Err(LabeledError::new("Invalid transport")
+                    .with_label("Must be 'tcp' or 'unixsock-stream'", span)
+                    .into())
+            }
+        };
+
+        if routers + peers + clients == 0 {
+            return Err(LabeledError::new("Empty sandbox")
+                .with_label("At least one router, peer or client is required", span)
+                .into());
+        }
+
+        if clients > 0 && routers + peers == 0 {
+            return Err(LabeledError::new("Unreachable clients")
+                .with_label("Clients need at least one router or peer", span)
+                .into());
+        }
+
+        let nodes = plan(
+            &sandbox,
+            routers,
+            peers,
+            clients,
+            unixsock_dir.as_ref().map(|dir| dir.path().to_path_buf()),
+        );
+
+        close_nodes(&self.state, &sandbox, span)?;
+        if let Some(dir) = unixsock_dir {
+            self.state
+                .sandbox_dirs
+                .lock()
+                .unwrap()
+                .insert(sandbox.clone(), dir);
+        }
+
+        // Nodes only connect to nodes opened before them, whose actual locators are known
+        let mut router_locators = Vec::with_capacity(routers);
+        let mut peer_locators = Vec::with_capacity(peers);
+
+        let mut opened = Vec::with_capacity(nodes.len());
+        for mut node in nodes {
+            node.connect = match node.mode {
+                WhatAmI::Router => router_locators.clone(),
+                _ if routers > 0 => router_locators.clone(),
+                _ => peer_locators.clone(),
+            };
+
+            match self.open(&node, span) {
+                Ok((sess, listen)) => {
+                    match node.mode {
+                        WhatAmI::Router => router_locators.extend(listen.iter().cloned()),
+                        WhatAmI::Peer => peer_locators.extend(listen.iter().cloned()),
+                        WhatAmI::Client => {}
+                    }
+                    opened.push(
+                        record!(
+                            "name" => node.name.clone().into_value(span),
+                            "mode" => node.mode.to_string().into_value(span),
+                            "zid" => sess.zid().to_string().into_value(span),
+                            "listen" => listen.into_value(span),
+                            "connect" => node.connect.into_value(span),
+                        )
+                        .into_value(span),
+                    )
+                }
+                Err(err) => {
+                    // Don't leave a half-built sandbox behind, but report why it failed
+                    if let Err(cleanup) = close_nodes(&self.state, &sandbox, span) {
+                        tracing::warn!(
+                            "Failed to close sandbox '{sandbox}' after an error: {}",
+                            cleanup.msg
+                        );
+                    }
+                    return Err(err.into());
+                }
+            }
+        }
+
+        Ok(PipelineData::Value(Value::list(opened, span), None))
+    }
+}
+
+impl Up {
+    /// Opens a sandbox node and registers it in the shell state, returning its session and the
+    /// locators it actually listens on.
+    fn open(&self, node: &Node, span: Span) -> Result<(Session, Vec<String>), LabeledError> {
+        let config = node.config().map_err(|e| {
+            LabeledError::new(format!("Failed to configure sandbox node '{}'", node.name))
+                .with_label(e.to_string(), span)
+        })?;
+
+        let runtime = start_runtime(config, span)?;
+        self.state
+            .runtimes
+            .write()
+            .unwrap()
+            .insert(node.name.clone(), runtime.clone());
+
+        let listen = runtime
+            .get_locators()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        let sess = session::init(runtime).wait().map_err(|e| {
+            LabeledError::new("Failed to open Zenoh session")
+                .with_label(format!("Could not establish Zenoh session: {e}"), span)
+        })?;
+
+        self.state
+            .sessions
+            .write()
+            .unwrap()
+            .insert(node.name.clone(), sess.clone());
+        self.state
+            .session_opened(&node.name, SessionSource::Runtime(node.name.clone()));
+
+        Ok((sess, listen))
+    }
+}
+
+struct Node {
+    name: String,
+    mode: WhatAmI,
+    listen: Vec<String>,
+    connect: Vec<String>,
+}
+
+impl Node {
+    fn config(&self) -> zenoh::Result<zenoh::Config> {
+        /// Formats a list of strings as a JSON array
+        fn json_list(items: &[String]) -> String {
+            nu_json::Value::Array(items.iter().cloned().map(nu_json::Value::String).collect())
+                .to_string()
+        }
+
+        let mut config = zenoh::Config::default();
+        config.insert_json5("mode", &format!("\"{}\"", self.mode))?;
+        config.insert_json5("listen/endpoints", &json_list(&self.listen))?;
+        config.insert_json5("connect/endpoints", &json_list(&self.connect))?;
+        config.insert_json5("scouting/multicast/enabled", "false")?;
+        Ok(config)
+    }
+}
+
+/// Lists the nodes of a sandbox in opening order; their connect endpoints are only known once
+/// the nodes before them listen. Without a unix socket directory, nodes listen over TCP.
+fn plan(
+    sandbox: &str,
+    routers: usize,
+    peers: usize,
+    clients: usize,
+    unixsock_dir: Option<PathBuf>,
+) -> Vec<Node> {
+    let prefix = node_prefix(sandbox);
+
+    let endpoint = |name: &str| match &unixsock_dir {
+        None => "tcp/127.0.0.1:0".to_string(),
+        Some(dir) => format!(
+            "unixsock-stream/{}",
+            dir.join(format!("{name}.sock")).display()
+        ),
+    };
+
+    let node = |mode: WhatAmI, i: usize| {
+        let name = format!("{mode}-{i}");
+        Node {
+            listen: match mode {
+                WhatAmI::Client => vec![],
+                _ => vec![endpoint(&name)],
+            },
+            name: format!("{prefix}{name}"),
+            mode,
+            connect: vec![],
+        }
+    };
+
+    (0..routers)
+        .map(|i| node(WhatAmI::Router, i))
+        .chain((0..peers).map(|i| node(WhatAmI::Peer, i)))
+        .chain((0..clients).map(|i| node(WhatAmI::Client, i)))
+        .collect()
+}
+
+/// Reads a non-negative node count flag (defaults to zero).
+fn count(
+    call: &Call,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    name: &str,
+) -> Result<usize, ShellError> {
+    match call.get_flag::<i64>(engine_state, stack, name)? {
+        None => Ok(0),
+        Some(n) => usize::try_from(n).map_err(|_| {
+            LabeledError::new(format!("Invalid number of {name}"))
+                .with_label("Must be a non-negative integer", call.head)
+                .into()
+        }),
+    }
+}
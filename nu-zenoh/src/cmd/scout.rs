@@ -11,14 +11,26 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
+use std::{collections::HashMap, path::PathBuf, str::FromStr};
+
+use chrono::{DateTime, FixedOffset, Local};
+use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    record, IntoValue, ListStream, PipelineData, ShellError, Signature, Type,
+    record, IntoValue, LabeledError, ListStream, PipelineData, ShellError, Signals, Signature,
+    Span, SyntaxShape, Type, Value,
+};
+use zenoh::{
+    config::{Locator, WhatAmI, WhatAmIMatcher, ZenohId},
+    scouting::Hello,
+    Wait,
 };
-use zenoh::{config::WhatAmIMatcher, Wait};
 
 use crate::{
-    call_ext2::CallExt2, interruptible_channel::InterruptibleChannel, signature_ext::SignatureExt,
+    call_ext2::CallExt2,
+    completion, conv, flow_control,
+    interruptible_channel::{InterruptibleChannel, StopConditions},
+    signature_ext::SignatureExt,
     State,
 };
 
@@ -42,13 +54,51 @@ impl Command for Scout {
         Signature::build(self.name())
             .session()
             .zenoh_category()
-            .input_output_type(Type::Nothing, Type::record())
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .named(
+                "what",
+                SyntaxShape::String,
+                "Comma-separated kinds of nodes to scout for (e.g. 'router,peer', defaults to all)",
+                Some('w'),
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "Stop scouting after this duration (defaults to until interrupted)",
+                Some('t'),
+            )
+            .named(
+                "config",
+                SyntaxShape::Record(vec![]),
+                "Zenoh configuration used for scouting (defaults to the session's)",
+                None,
+            )
+            .named(
+                "config-file",
+                SyntaxShape::Filepath,
+                "Path to a Zenoh configuration file used for scouting",
+                None,
+            )
+            .switch(
+                "dedup",
+                "Merge hellos of the same node into one row, emitted when scouting ends",
+                Some('d'),
+            )
+            .flow_control()
     }
 
     fn description(&self) -> &str {
         "Scout the Zenoh network"
     }
 
+    fn extra_description(&self) -> &str {
+        "Only the 'scouting' section of the configuration is relevant. \
+        With --dedup, each row has the first-seen and last-seen times of a node and the union \
+        of its advertised locators."
+    }
+
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -58,35 +108,164 @@ impl Command for Scout {
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
 
-        const SCOUT_CHANNEL_SIZE: usize = 256;
-        let (tx, rx) = flume::bounded(SCOUT_CHANNEL_SIZE);
+        let what = match call.get_flag::<String>(engine_state, stack, "what")? {
+            Some(what) => parse_what(&what, span)?,
+            None => WhatAmIMatcher::empty().client().peer().router(),
+        };
+        let timeout = call.timeout(engine_state, stack)?;
+        let dedup = call.has_flag(engine_state, stack, "dedup")?;
 
-        let config = self
-            .state
-            .with_session(&call.session(engine_state, stack)?, |sess| {
-                sess.config().lock().clone()
-            })?;
+        let config_record = call.get_flag::<Value>(engine_state, stack, "config")?;
+        let config_file = call.get_flag::<PathBuf>(engine_state, stack, "config-file")?;
+        let config = match (config_record, config_file) {
+            (Some(config_record), None) => {
+                let json_value =
+                    conv::value_to_json_value(engine_state, &config_record, span, false)?;
+                zenoh::Config::from_json5(&json_value.to_string()).map_err(|e| {
+                    LabeledError::new("Failed to parse config record")
+                        .with_label(format!("Could not parse config record: {e}"), span)
+                })?
+            }
+            (None, Some(config_file)) => zenoh::Config::from_file(&config_file).map_err(|e| {
+                LabeledError::new("Failed to load config file").with_label(
+                    format!("Could not read config from {}: {e}", config_file.display()),
+                    span,
+                )
+            })?,
+            (None, None) => self
+                .state
+                .with_session(&call.session(engine_state, stack)?, |sess| {
+                    sess.config().lock().clone()
+                })?,
+            (Some(_), Some(_)) => {
+                return Err(LabeledError::new("Conflicting arguments")
+                    .with_label(
+                        "Only one of --config or --config-file can be specified",
+                        span,
+                    )
+                    .into())
+            }
+        };
 
-        let scout = zenoh::scout(WhatAmIMatcher::empty().client().peer().router(), config)
-            .callback(move |scout| {
-                let _ = tx.send(scout);
+        let (tx, rx, dropped) = flow_control::channel(call.flow_control(engine_state, stack)?);
+
+        let scout = zenoh::scout(what, config)
+            .callback(move |hello| {
+                tx.send((Local::now().fixed_offset(), hello));
             })
             .wait()
             .map_err(|e| {
-                nu_protocol::LabeledError::new("Scout operation failed")
+                LabeledError::new("Scout operation failed")
                     .with_label(format!("Zenoh scout failed: {e}"), call.head)
             })?;
 
-        let iter = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), scout)
-            .map(move |hello| {
-                record!(
-                    "zid" => hello.zid().to_string().into_value(span),
-                    "whatami" => hello.whatami().to_string().into_value(span),
-                    "locators" => hello.locators().iter().map(|l| l.to_string().into_value(span)).collect::<Vec<_>>().into_value(span),
-                )
-                .into_value(span)
+        let hellos = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), scout)
+            .with_stop_conditions(StopConditions {
+                duration: timeout,
+                ..Default::default()
             });
 
-        Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+        if dedup {
+            // Hellos are only merged once polled, i.e. when the stream is consumed
+            let iter = std::iter::once(hellos)
+                .flat_map(move |hellos| dedup_hellos(hellos, span))
+                .chain(dropped.report(span));
+
+            // The channel already stops on interruption, the merged rows must outlive it
+            Ok(ListStream::new(iter, span, Signals::empty()).into())
+        } else {
+            let iter = hellos
+                .map(move |(_, hello)| {
+                    record!(
+                        "zid" => hello.zid().to_string().into_value(span),
+                        "whatami" => hello.whatami().to_string().into_value(span),
+                        "locators" => locators_value(hello.locators(), span),
+                    )
+                    .into_value(span)
+                })
+                .chain(dropped.report(span));
+
+            Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+        }
+    }
+}
+
+/// Parses a comma-separated list of node kinds.
+fn parse_what(what: &str, span: Span) -> Result<WhatAmIMatcher, LabeledError> {
+    what.split(',')
+        .map(|kind| {
+            WhatAmI::from_str(kind.trim()).map_err(|_| {
+                LabeledError::new("Invalid node kind").with_label(
+                    format!("'{kind}' must be 'router', 'peer' or 'client'"),
+                    span,
+                )
+            })
+        })
+        .try_fold(WhatAmIMatcher::empty(), |matcher, kind| {
+            Ok(match kind {
+                WhatAmI::Router => matcher.router(),
+                WhatAmI::Peer => matcher.peer(),
+                WhatAmI::Client => matcher.client(),
+            })
+        })
+}
+
+/// A node seen while scouting.
+struct Node {
+    whatami: WhatAmI,
+    locators: Vec<Locator>,
+    first_seen: DateTime<FixedOffset>,
+    last_seen: DateTime<FixedOffset>,
+}
+
+/// Merges hellos per zid, in order of first appearance.
+fn dedup_hellos(
+    hellos: impl Iterator<Item = (DateTime<FixedOffset>, Hello)>,
+    span: Span,
+) -> Vec<Value> {
+    let mut order = Vec::<ZenohId>::new();
+    let mut nodes = HashMap::<ZenohId, Node>::new();
+
+    for (time, hello) in hellos {
+        let node = nodes.entry(hello.zid()).or_insert_with(|| {
+            order.push(hello.zid());
+            Node {
+                whatami: hello.whatami(),
+                locators: vec![],
+                first_seen: time,
+                last_seen: time,
+            }
+        });
+
+        node.whatami = hello.whatami();
+        node.last_seen = time;
+        for locator in hello.locators() {
+            if !node.locators.contains(locator) {
+                node.locators.push(locator.clone());
+            }
+        }
     }
+
+    order
+        .into_iter()
+        .filter_map(|zid| nodes.remove(&zid).map(|node| (zid, node)))
+        .map(|(zid, node)| {
+            record!(
+                "zid" => zid.to_string().into_value(span),
+                "whatami" => node.whatami.to_string().into_value(span),
+                "locators" => locators_value(&node.locators, span),
+                "first_seen" => Value::date(node.first_seen, span),
+                "last_seen" => Value::date(node.last_seen, span),
+            )
+            .into_value(span)
+        })
+        .collect()
+}
+
+fn locators_value(locators: &[Locator], span: Span) -> Value {
+    locators
+        .iter()
+        .map(|l| l.to_string().into_value(span))
+        .collect::<Vec<_>>()
+        .into_value(span)
 }
@@ -4,7 +4,7 @@ use nu_protocol::{
 };
 use zenoh::Wait;
 
-use crate::{call_ext2::CallExt2, signature_ext::SignatureExt, State};
+use crate::{call_ext2::CallExt2, completion, signature_ext::SignatureExt, State};
 
 #[derive(Clone)]
 pub(crate) struct Close {
@@ -33,6 +33,8 @@ impl Command for Close {
         "Close a session"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -43,6 +45,7 @@ impl Command for Close {
         let session_name = call.session(engine_state, stack)?;
         let mut sessions = self.state.sessions.write().unwrap();
         if let Some(sess) = sessions.remove(&session_name) {
+            self.state.session_closed(&session_name);
             sess.close().wait().map_err(|e| {
                 nu_protocol::LabeledError::new("Failed to close Zenoh session '{session_name}'")
                     .with_label(format!("Could not close Zenoh session: {e}"), call.head)
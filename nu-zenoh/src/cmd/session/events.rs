@@ -0,0 +1,256 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{DateTime, FixedOffset, Local};
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, ListStream, PipelineData, ShellError, Signature, Span, Type,
+    Value,
+};
+use zenoh::{
+    config::{Locator, WhatAmI, ZenohId},
+    sample::SampleKind,
+    session::{LinkEvent, TransportEvent},
+    Session, Wait,
+};
+
+use crate::{
+    call_ext2::CallExt2, completion, flow_control, interruptible_channel::InterruptibleChannel,
+    signature_ext::SignatureExt, State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Events {
+    state: State,
+}
+
+impl Events {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Events {
+    fn name(&self) -> &str {
+        "zenoh session events"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .switch(
+                "history",
+                "Start with events for transports and links that are already open",
+                Some('H'),
+            )
+            .flow_control()
+    }
+
+    fn description(&self) -> &str {
+        "Stream session connectivity events"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Emits a record when a transport to a peer or router opens or closes \
+        ('transport_opened', 'transport_closed'), when a link is added or removed \
+        ('link_added', 'link_removed'), and a final 'session_closed' record when the session \
+        itself is closed."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let history = call.has_flag(engine_state, stack, "history")?;
+        let (tx, rx, dropped) = flow_control::channel(call.flow_control(engine_state, stack)?);
+
+        let session_name = call.session(engine_state, stack)?;
+        let session = self.state.with_session(&session_name, Session::clone)?;
+
+        let tx = Arc::new(tx);
+        let transport_tx = tx.clone();
+        let transports = session
+            .info()
+            .transport_events_listener()
+            .history(history)
+            .callback(move |event| transport_tx.send((now(), Event::Transport(event))))
+            .wait()
+            .map_err(|e| {
+                LabeledError::new("Transport events listener declaration failed")
+                    .with_label(format!("Zenoh transport events listener failed: {e}"), span)
+            })?;
+        let links = session
+            .info()
+            .link_events_listener()
+            .history(history)
+            .callback(move |event| tx.send((now(), Event::Link(event))))
+            .wait()
+            .map_err(|e| {
+                LabeledError::new("Link events listener declaration failed")
+                    .with_label(format!("Zenoh link events listener failed: {e}"), span)
+            })?;
+
+        let zid = session.zid();
+        let (events, closed) = InterruptibleChannel::with_data(
+            rx,
+            engine_state.signals().clone(),
+            (transports, links),
+        )
+        .watch_session(session);
+
+        let mut peers = Peers::new(session.clone());
+        let iter = events
+            .map(move |(time, event)| peers.record(time, event, span))
+            .chain(dropped.report(span))
+            .chain(std::iter::once(closed).filter_map(move |closed| {
+                closed
+                    .is_closed()
+                    .then(|| event_record(now(), "session_closed", zid, None, None, span))
+            }));
+
+        Ok(ListStream::new(iter, span, engine_state.signals().clone()).into())
+    }
+}
+
+enum Event {
+    Transport(TransportEvent),
+    Link(LinkEvent),
+}
+
+/// Known remote nodes, used to attach a whatami to link events and a locator to transport events.
+struct Peers {
+    /// Queried for the links of a transport when it opens, as its link events may come later.
+    session: Session,
+    whatami: HashMap<ZenohId, WhatAmI>,
+    locators: HashMap<ZenohId, Vec<Locator>>,
+}
+
+impl Peers {
+    fn new(session: Session) -> Self {
+        Self {
+            session,
+            whatami: HashMap::new(),
+            locators: HashMap::new(),
+        }
+    }
+
+    fn record(&mut self, time: DateTime<FixedOffset>, event: Event, span: Span) -> Value {
+        match event {
+            Event::Transport(event) => {
+                let transport = event.transport();
+                let zid = *transport.zid();
+
+                if event.kind() == SampleKind::Put {
+                    let locators = self.locators.entry(zid).or_default();
+                    for link in self
+                        .session
+                        .info()
+                        .links()
+                        .transport(transport.clone())
+                        .wait()
+                    {
+                        if !locators.contains(link.dst()) {
+                            locators.push(link.dst().clone());
+                        }
+                    }
+                }
+                let locator = self
+                    .locators
+                    .get(&zid)
+                    .and_then(|locators| locators.first())
+                    .cloned();
+
+                let name = match event.kind() {
+                    SampleKind::Put => {
+                        self.whatami.insert(zid, transport.whatami());
+                        "transport_opened"
+                    }
+                    SampleKind::Delete => {
+                        self.whatami.remove(&zid);
+                        self.locators.remove(&zid);
+                        "transport_closed"
+                    }
+                };
+
+                event_record(
+                    time,
+                    name,
+                    zid,
+                    Some(transport.whatami()),
+                    locator.as_ref(),
+                    span,
+                )
+            }
+            Event::Link(event) => {
+                let link = event.link();
+                let zid = *link.zid();
+                let locators = self.locators.entry(zid).or_default();
+
+                let name = match event.kind() {
+                    SampleKind::Put => {
+                        if !locators.contains(link.dst()) {
+                            locators.push(link.dst().clone());
+                        }
+                        "link_added"
+                    }
+                    SampleKind::Delete => {
+                        locators.retain(|locator| locator != link.dst());
+                        "link_removed"
+                    }
+                };
+
+                event_record(
+                    time,
+                    name,
+                    zid,
+                    self.whatami.get(&zid).copied(),
+                    Some(link.dst()),
+                    span,
+                )
+            }
+        }
+    }
+}
+
+fn event_record(
+    time: DateTime<FixedOffset>,
+    event: &str,
+    zid: ZenohId,
+    whatami: Option<WhatAmI>,
+    locator: Option<&Locator>,
+    span: Span,
+) -> Value {
+    record!(
+        "time" => Value::date(time, span),
+        "event" => Value::string(event, span),
+        "zid" => zid.to_string().into_value(span),
+        "whatami" => whatami.map(|w| w.to_string().into_value(span)).unwrap_or_default(),
+        "locator" => locator.map(|l| l.to_string().into_value(span)).unwrap_or_default(),
+    )
+    .into_value(span)
+}
+
+fn now() -> DateTime<FixedOffset> {
+    Local::now().fixed_offset()
+}
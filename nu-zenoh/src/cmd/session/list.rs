@@ -13,10 +13,16 @@
 //
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    record, IntoValue, PipelineData, ShellError, Signature, Type, Value,
+    record, IntoValue, PipelineData, ShellError, Signature, Span, Type, Value,
 };
+use zenoh::{Session, Wait};
 
-use crate::{signature_ext::SignatureExt, State};
+use crate::{
+    call_ext2::CallExt2,
+    session_meta::{SessionMeta, SessionSource},
+    signature_ext::SignatureExt,
+    State,
+};
 
 #[derive(Clone)]
 pub(crate) struct List {
@@ -37,33 +43,128 @@ impl Command for List {
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .zenoh_category()
-            .input_output_type(Type::Nothing, Type::record())
+            .input_output_type(Type::Nothing, Type::table())
     }
 
     fn description(&self) -> &str {
         "List opened sessions"
     }
 
+    fn extra_description(&self) -> &str {
+        "The 'current' session is the one used when no --session is given. The 'source' is either \
+        'default', 'config-file', 'record', 'scout' or 'runtime'. Endpoints are those of the \
+        session configuration."
+    }
+
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
+        let current = call.session(engine_state, stack)?;
+
         let sessions = self.state.sessions.read().unwrap();
-        let session_list = sessions
-            .iter()
-            .map(|(name, sess)| {
-                record!(
-                    "name" => name.clone().into_value(span),
-                    "zid" => sess.zid().to_string().into_value(span)
+        let session_meta = self.state.session_meta.read().unwrap();
+
+        let mut names = sessions.keys().collect::<Vec<_>>();
+        names.sort();
+
+        let session_list = names
+            .into_iter()
+            .map(|name| {
+                session_record(
+                    name,
+                    &sessions[name],
+                    session_meta.get(name),
+                    *name == current,
+                    span,
                 )
-                .into_value(span)
             })
             .collect::<Vec<_>>();
 
         Ok(PipelineData::Value(Value::list(session_list, span), None))
     }
 }
+
+fn session_record(
+    name: &str,
+    sess: &Session,
+    meta: Option<&SessionMeta>,
+    current: bool,
+    span: Span,
+) -> Value {
+    let config = nu_json::from_str::<nu_json::Value>(&sess.config().lock().to_string()).ok();
+    let mode = config
+        .as_ref()
+        .and_then(|config| config.find("mode"))
+        .and_then(|mode| mode.as_str())
+        .unwrap_or("peer")
+        .to_string();
+    let endpoints = |kind: &str| {
+        config
+            .as_ref()
+            .and_then(|config| config.find_path(&[kind, "endpoints"]))
+            .map(|endpoints| mode_dependent(endpoints, &mode))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|endpoint| Value::string(endpoint, span))
+            .collect::<Vec<_>>()
+    };
+
+    let info = sess.info();
+    let source = meta.map(|meta| &meta.source);
+
+    Value::record(
+        record!(
+            "name" => Value::string(name, span),
+            "current" => current.into_value(span),
+            "zid" => sess.zid().to_string().into_value(span),
+            "mode" => mode.clone().into_value(span),
+            "opened" => meta
+                .map(|meta| Value::date(meta.opened, span))
+                .unwrap_or_default(),
+            "source" => source
+                .map(|source| Value::string(source.kind(), span))
+                .unwrap_or_default(),
+            "config_file" => match source {
+                Some(SessionSource::ConfigFile(path)) => {
+                    Value::string(path.display().to_string(), span)
+                }
+                _ => Value::nothing(span),
+            },
+            "runtime" => match source {
+                Some(SessionSource::Runtime(runtime)) => Value::string(runtime, span),
+                _ => Value::nothing(span),
+            },
+            "listen" => Value::list(endpoints("listen"), span),
+            "connect" => Value::list(endpoints("connect"), span),
+            "peers" => (info.peers_zid().wait().count() as i64).into_value(span),
+            "routers" => (info.routers_zid().wait().count() as i64).into_value(span),
+        ),
+        span,
+    )
+}
+
+/// Reads a list of endpoints which may depend on the mode (e.g. '{ router: [...], peer: [...] }').
+fn mode_dependent(value: &nu_json::Value, mode: &str) -> Vec<String> {
+    let value = match value {
+        nu_json::Value::Object(_) => match value.find(mode) {
+            Some(value) => value,
+            None => return vec![],
+        },
+        value => value,
+    };
+
+    value
+        .as_array()
+        .map(|endpoints| {
+            endpoints
+                .iter()
+                .filter_map(|endpoint| endpoint.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -12,5 +12,8 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 pub(crate) mod close;
+pub(crate) mod events;
 pub(crate) mod list;
 pub(crate) mod open;
+pub(crate) mod reopen;
+pub(crate) mod select;
@@ -11,16 +11,19 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
 use nu_engine::CallExt;
 use nu_protocol::{
     engine::{Call, Command, EngineState, Stack},
-    LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+    LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
-use zenoh::{session, Wait};
+use zenoh::{config::Locator, session, Wait};
 
-use crate::{call_ext2::CallExt2, conv, signature_ext::SignatureExt, State};
+use crate::{
+    call_ext2::CallExt2, completion, conv, session_meta::SessionSource,
+    signature_ext::SignatureExt, State,
+};
 
 #[derive(Clone)]
 pub(crate) struct Open {
@@ -42,7 +45,16 @@ impl Command for Open {
         let sig = Signature::build(self.name())
             .session()
             .zenoh_category()
-            .input_output_type(Type::Nothing, Type::Nothing)
+            .input_output_types(vec![
+                (Type::Nothing, Type::Nothing),
+                (Type::list(Type::record()), Type::Nothing),
+                (Type::record(), Type::Nothing),
+            ])
+            .switch(
+                "connect-from-scout",
+                "Open a client connecting to the nodes of the input 'zenoh scout' records",
+                None,
+            )
             .named(
                 "config-file",
                 SyntaxShape::Filepath,
@@ -66,18 +78,39 @@ impl Command for Open {
         "(re)Open a session"
     }
 
+    fn extra_description(&self) -> &str {
+        "With --connect-from-scout, one locator is picked per scouted node (preferring TCP, \
+        then UDP, then any other protocol) and the session is opened in client mode with those \
+        as connect endpoints, on top of the given configuration if any; input records without \
+        locators are ignored. Reopening an existing session drops its declarations, see \
+        'zenoh session reopen' to keep them."
+    }
+
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
         stack: &mut Stack,
         call: &Call,
-        _input: PipelineData,
+        input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         // FIXME(fuzzypixelz): refactor this (see 'zenoh runtime open')
 
         let file_path = call.get_flag::<PathBuf>(engine_state, stack, "config-file")?;
         let runtime_name = call.get_flag::<String>(engine_state, stack, "runtime")?;
         let config_record = call.opt::<Value>(engine_state, stack, 0)?;
+        let connect_from_scout = call.has_flag(engine_state, stack, "connect-from-scout")?;
+
+        if connect_from_scout && runtime_name.is_some() {
+            return Err(ShellError::GenericError {
+                error: "Conflicting arguments".to_string(),
+                msg: "--connect-from-scout cannot be used with --runtime".to_string(),
+                span: Some(call.head),
+                help: None,
+                inner: vec![],
+            });
+        }
 
         let config = match (
             file_path.as_ref(),
@@ -124,6 +157,7 @@ impl Command for Open {
                 let session_name = call.session(engine_state, stack)?;
                 let mut sessions = self.state.sessions.write().unwrap();
                 if let Some(sess) = sessions.remove(&session_name) {
+                    self.state.session_closed(&session_name);
                     sess.close().wait().map_err(|e| {
                         nu_protocol::LabeledError::new(
                             "Failed to reopen Zenoh session '{session_name}'",
@@ -135,7 +169,9 @@ impl Command for Open {
                     nu_protocol::LabeledError::new("Failed to open Zenoh session")
                         .with_label(format!("Could not establish Zenoh session: {e}"), call.head)
                 })?;
-                sessions.insert(session_name, new_session);
+                sessions.insert(session_name.clone(), new_session);
+                self.state
+                    .session_opened(&session_name, SessionSource::Runtime(runtime_name.clone()));
                 return Ok(PipelineData::Value(Value::nothing(call.head), None));
             }
             (None, None, None) => zenoh::Config::default(),
@@ -151,9 +187,24 @@ impl Command for Open {
             }
         };
 
+        let (config, source) = if connect_from_scout {
+            (
+                connect_from_scout_config(config, input, call.head)?,
+                SessionSource::Scout,
+            )
+        } else {
+            let source = match (file_path, config_record) {
+                (Some(file_path), _) => SessionSource::ConfigFile(file_path),
+                (None, Some(_)) => SessionSource::Record,
+                (None, None) => SessionSource::Default,
+            };
+            (config, source)
+        };
+
         let session_name = call.session(engine_state, stack)?;
         let mut sessions = self.state.sessions.write().unwrap();
         if let Some(sess) = sessions.remove(&session_name) {
+            self.state.session_closed(&session_name);
             sess.close().wait().map_err(|e| {
                 nu_protocol::LabeledError::new("Failed to reopen Zenoh session '{session_name}'")
                     .with_label(format!("Could not close Zenoh session: {e}"), call.head)
@@ -163,8 +214,78 @@ impl Command for Open {
             nu_protocol::LabeledError::new("Failed to open Zenoh session")
                 .with_label(format!("Could not establish Zenoh session: {e}"), call.head)
         })?;
-        sessions.insert(session_name, new_session);
+        sessions.insert(session_name.clone(), new_session);
+        self.state.session_opened(&session_name, source);
 
         Ok(PipelineData::Value(Value::nothing(call.head), None))
     }
 }
+
+/// Builds a client configuration connecting to the nodes of scout records.
+fn connect_from_scout_config(
+    mut config: zenoh::Config,
+    input: PipelineData,
+    span: Span,
+) -> Result<zenoh::Config, ShellError> {
+    /// Lower is better
+    fn rank(locator: &Locator) -> u8 {
+        match locator.protocol().as_str() {
+            "tcp" => 0,
+            "udp" => 1,
+            _ => 2,
+        }
+    }
+
+    let mut endpoints = vec![];
+    for node in input {
+        if let Value::Error { error, .. } = node {
+            return Err(*error);
+        }
+
+        // Skip trailing report records, e.g. '{dropped: n}'
+        let Some(locators) = node.as_record()?.get("locators") else {
+            continue;
+        };
+        let locators = locators
+            .as_list()?
+            .iter()
+            .map(|locator| {
+                let locator = locator.as_str()?;
+                Locator::from_str(locator).map_err(|e| {
+                    ShellError::from(LabeledError::new("Invalid locator").with_label(
+                        format!("Could not parse locator '{locator}': {e}"),
+                        node.span(),
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(locator) = locators.iter().min_by_key(|locator| rank(locator)) {
+            endpoints.push(nu_json::Value::String(locator.to_endpoint().to_string()));
+        }
+    }
+
+    if endpoints.is_empty() {
+        return Err(LabeledError::new("No nodes to connect to")
+            .with_label(
+                "Expected 'zenoh scout' records with locators as input",
+                span,
+            )
+            .into());
+    }
+
+    let insert = |config: &mut zenoh::Config, key: &str, value: &str| {
+        config.insert_json5(key, value).map_err(|e| {
+            LabeledError::new("Failed to build client config")
+                .with_label(format!("Could not set '{key}': {e}"), span)
+        })
+    };
+    insert(&mut config, "mode", "\"client\"")?;
+    insert(
+        &mut config,
+        "connect/endpoints",
+        &nu_json::Value::Array(endpoints).to_string(),
+    )?;
+
+    Ok(config)
+}
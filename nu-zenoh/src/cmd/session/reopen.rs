@@ -0,0 +1,245 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::path::PathBuf;
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, LabeledError, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value,
+};
+use zenoh::{internal::runtime::Runtime, session, Session, Wait};
+
+use crate::{completion, conv, session_meta::SessionSource, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Reopen {
+    state: State,
+}
+
+impl Reopen {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Reopen {
+    fn name(&self) -> &str {
+        "zenoh session reopen"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::table())
+            .required("session", SyntaxShape::String, "Session name")
+            .named(
+                "config-file",
+                SyntaxShape::Filepath,
+                "Path to a Zenoh configuration file",
+                None,
+            )
+            .optional(
+                "config",
+                SyntaxShape::Record(vec![]),
+                "Zenoh configuration object (see https://github.com/eclipse-zenoh/zenoh/blob/main/DEFAULT_CONFIG.json5)",
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Reopen a session, declaring its subscribers, queryables and liveliness tokens again"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Subscribers ('zenoh sub'), queryables ('zenoh reply') and liveliness tokens \
+        ('zenoh liveliness decl') of the old session are declared on the new one, so that their \
+        streams and token values keep working. The old session is closed first, so that the new \
+        one can listen on the same endpoints; if the new one fails to open, the session is lost \
+        and its declarations end. Without a configuration, the session is reopened with its current configuration (or on its \
+        runtime). Returns the declarations and whether they were declared again."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let session_name = call.req::<Spanned<String>>(engine_state, stack, 0)?;
+        let file_path = call.get_flag::<PathBuf>(engine_state, stack, "config-file")?;
+        let config_record = call.opt::<Value>(engine_state, stack, 1)?;
+
+        let old_session = self
+            .state
+            .with_session(&session_name.item, Session::clone)
+            .map_err(|err| err.with_label("Unknown session", session_name.span))?;
+        let previous_source = self
+            .state
+            .session_meta
+            .read()
+            .unwrap()
+            .get(&session_name.item)
+            .map(|meta| meta.source.clone());
+
+        // Resolve the new configuration first: nothing is closed if it is invalid.
+        let (target, source) = match (file_path, config_record) {
+            (Some(file_path), None) => {
+                let config = zenoh::Config::from_file(&file_path).map_err(|e| {
+                    LabeledError::new("Failed to load config file").with_label(
+                        format!("Could not read config from {}: {}", file_path.display(), e),
+                        span,
+                    )
+                })?;
+                (Target::Config(config), SessionSource::ConfigFile(file_path))
+            }
+            (None, Some(config_record)) => {
+                let json_value =
+                    conv::value_to_json_value(engine_state, &config_record, span, false)?;
+                let config = zenoh::Config::from_json5(&json_value.to_string()).map_err(|e| {
+                    LabeledError::new("Failed to parse config record")
+                        .with_label(format!("Could not parse config record: {e}"), span)
+                })?;
+                (Target::Config(config), SessionSource::Record)
+            }
+            (None, None) => match previous_source {
+                Some(SessionSource::Runtime(runtime_name)) => {
+                    let runtime = self
+                        .state
+                        .runtimes
+                        .read()
+                        .unwrap()
+                        .get(&runtime_name)
+                        .ok_or_else(|| {
+                            LabeledError::new(format!("runtime '{runtime_name}' was not found"))
+                        })?
+                        .clone();
+                    (
+                        Target::Runtime(runtime),
+                        SessionSource::Runtime(runtime_name),
+                    )
+                }
+                source => (
+                    Target::Config(current_config(&old_session, span)?),
+                    source.unwrap_or(SessionSource::Default),
+                ),
+            },
+            (Some(_), Some(_)) => {
+                return Err(ShellError::GenericError {
+                    error: "Conflicting arguments".to_string(),
+                    msg: "Only one of RECORD or --config-file can be specified".to_string(),
+                    span: Some(span),
+                    help: None,
+                    inner: vec![],
+                });
+            }
+        };
+
+        // The old session is closed before the new one is opened, so that it releases the
+        // endpoints it listens on.
+        self.state.declarations.detach(&session_name.item);
+        if let Err(e) = old_session.close().wait() {
+            self.state
+                .declarations
+                .redeclare(&session_name.item, &old_session);
+            return Err(LabeledError::new(format!(
+                "Failed to close the previous Zenoh session '{}'",
+                session_name.item
+            ))
+            .with_label(format!("Could not close Zenoh session: {e}"), span)
+            .into());
+        }
+
+        let opened = match target {
+            Target::Config(config) => zenoh::open(config).wait(),
+            Target::Runtime(runtime) => session::init(runtime).wait(),
+        };
+        let new_session = match opened {
+            Ok(new_session) => new_session,
+            Err(e) => {
+                self.state
+                    .sessions
+                    .write()
+                    .unwrap()
+                    .remove(&session_name.item);
+                self.state.session_closed(&session_name.item);
+                return Err(LabeledError::new("Failed to open Zenoh session")
+                    .with_label(format!("Could not establish Zenoh session: {e}"), span)
+                    .with_help(format!(
+                        "The previous session was closed: session '{}' was lost",
+                        session_name.item
+                    ))
+                    .into());
+            }
+        };
+
+        self.state
+            .sessions
+            .write()
+            .unwrap()
+            .insert(session_name.item.clone(), new_session.clone());
+        self.state.session_opened(&session_name.item, source);
+
+        let declarations = self
+            .state
+            .declarations
+            .redeclare(&session_name.item, &new_session)
+            .into_iter()
+            .map(|(declaration, result)| {
+                Value::record(
+                    record! {
+                        "kind" => Value::string(declaration.kind(), span),
+                        "keyexpr" => Value::string(declaration.keyexpr(), span),
+                        "redeclared" => Value::bool(result.is_ok(), span),
+                        "error" => match result {
+                            Ok(()) => Value::nothing(span),
+                            Err(err) => Value::string(err.to_string(), span),
+                        },
+                    },
+                    span,
+                )
+            })
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(declarations, span), None))
+    }
+}
+
+/// What the session is reopened with.
+enum Target {
+    Config(zenoh::Config),
+    Runtime(Runtime),
+}
+
+/// The configuration of a session, without its id so that the new session gets its own.
+fn current_config(session: &Session, span: Span) -> Result<zenoh::Config, LabeledError> {
+    let invalid = |e: String| {
+        LabeledError::new("Failed to read the session configuration").with_label(
+            format!("Could not reuse the session configuration: {e}"),
+            span,
+        )
+    };
+
+    let mut config = nu_json::from_str::<nu_json::Value>(&session.config().lock().to_string())
+        .map_err(|e| invalid(e.to_string()))?;
+    if let nu_json::Value::Object(config) = &mut config {
+        config.remove("id");
+    }
+
+    zenoh::Config::from_json5(&config.to_string()).map_err(|e| invalid(e.to_string()))
+}
@@ -0,0 +1,86 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type, Value,
+};
+
+use crate::{completion, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Use {
+    state: State,
+}
+
+impl Use {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Use {
+    fn name(&self) -> &str {
+        "zenoh session use"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("session", SyntaxShape::String, "Session name")
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::Nothing)
+    }
+
+    fn description(&self) -> &str {
+        "Use a session by default"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Sets $env.ZENOH_SESSION, which commands use when no --session is given."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let session_name = call.req::<Spanned<String>>(engine_state, stack, 0)?;
+
+        if !self
+            .state
+            .sessions
+            .read()
+            .unwrap()
+            .contains_key(&session_name.item)
+        {
+            return Err(LabeledError::new("Session not found")
+                .with_label(
+                    format!("No session named '{}'", session_name.item),
+                    session_name.span,
+                )
+                .into());
+        }
+
+        stack.add_env_var(
+            State::SESSION_ENV_VAR.to_string(),
+            Value::string(session_name.item, session_name.span),
+        );
+
+        Ok(PipelineData::Value(Value::nothing(call.head), None))
+    }
+}
@@ -11,16 +11,26 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use nu_engine::CallExt;
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use nu_engine::{CallExt, ClosureEval};
 use nu_protocol::{
-    engine::{Call, Command, EngineState, Stack},
-    ListStream, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    engine::{Call, Closure, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, ListStream, PipelineData, ShellError, Signals, Signature,
+    Span, SyntaxShape, Type, Value,
 };
-use zenoh::Wait;
+use zenoh::{sample::Sample, session::ZenohId, Session, Wait};
 
 use crate::{
-    call_ext2::CallExt2, conv, interruptible_channel::InterruptibleChannel,
-    signature_ext::SignatureExt, State,
+    call_ext2::CallExt2,
+    completion, conv, flow_control,
+    interruptible_channel::{InterruptibleChannel, StopConditions},
+    signature_ext::SignatureExt,
+    State,
 };
 
 #[derive(Clone)]
@@ -46,12 +56,54 @@ impl Command for Sub {
             .input_output_type(Type::Nothing, Type::list(Type::record()))
             .required("keyexpr", SyntaxShape::String, "key-expression")
             .allowed_origin()
+            .flow_control()
+            .switch(
+                "detect-gaps",
+                "Track sequence numbers per source and annotate gaps, duplicates and reordering",
+                Some('g'),
+            )
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "Stop after receiving this many samples",
+                Some('n'),
+            )
+            .named(
+                "duration",
+                SyntaxShape::Duration,
+                "Stop after this duration",
+                Some('d'),
+            )
+            .named(
+                "idle-timeout",
+                SyntaxShape::Duration,
+                "Stop if no sample is received for this duration",
+                None,
+            )
+            .named(
+                "until",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "Stop after the first sample for which this closure returns true",
+                None,
+            )
     }
 
     fn description(&self) -> &str {
         "Zenoh Subscriber declaration"
     }
 
+    fn extra_description(&self) -> &str {
+        "The stream ends when interrupted, when the session is closed, or when one of the \
+        --count, --duration, --idle-timeout or --until conditions is met; the subscriber is \
+        undeclared right away. The sample matching --until is included in the stream; if the \
+        --until closure fails, the stream ends with its error. \
+        With --detect-gaps, each record gets 'gap', 'duplicate', 'reordered' and 'restart' fields \
+        computed from its source id and sequence number, and a summary record is emitted per source when \
+        the stream ends. Samples without source info are left unannotated."
+    }
+
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -59,33 +111,403 @@ impl Command for Sub {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        const SUB_CHANNEL_SIZE: usize = 256;
-        let (tx, rx) = flume::bounded(SUB_CHANNEL_SIZE);
+        let (tx, rx, dropped) = flow_control::channel(call.flow_control(engine_state, stack)?);
 
         let span = call.head;
 
         let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let detect_gaps = call.has_flag(engine_state, stack, "detect-gaps")?;
+        let (stop, until_error) = stop_conditions(engine_state, stack, call)?;
+
+        let session_name = call.session(engine_state, stack)?;
+        let session = self.state.with_session(&session_name, Session::clone)?;
+
+        let origin = call.allowed_origin(engine_state, stack)?;
+        let tx = Arc::new(tx);
 
         let sub = self
             .state
-            .with_session(&call.session(engine_state, stack)?, move |sess| {
-                let mut sub = sess.declare_subscriber(keyexpr).callback(move |sample| {
-                    let _ = tx.send(sample);
-                });
+            .declarations
+            .declare(
+                &session_name,
+                &session,
+                "subscriber",
+                keyexpr.clone(),
+                move |sess| {
+                    let tx = tx.clone();
 
-                if let Some(origin) = call.allowed_origin(engine_state, stack)? {
-                    sub = sub.allowed_origin(origin);
-                }
-                sub.wait()
-            })?
+                    let mut sub = sess
+                        .declare_subscriber(keyexpr.clone())
+                        .callback(move |sample| tx.send(sample));
+
+                    if let Some(origin) = origin {
+                        sub = sub.allowed_origin(origin);
+                    }
+                    sub.wait()
+                },
+            )
             .map_err(|e| {
                 nu_protocol::LabeledError::new("Subscriber declaration failed")
                     .with_label(format!("Zenoh subscriber failed: {e}"), call.head)
             })?;
 
-        let iter = InterruptibleChannel::with_data(rx, engine_state.signals().clone(), sub)
-            .map(move |sample| conv::sample_to_record_value(sample, span));
+        let (samples, closed) =
+            InterruptibleChannel::with_data(rx, engine_state.signals().clone(), sub.clone())
+                .with_stop_conditions(stop)
+                .watch_declaration(sub);
+
+        let mut recorder = self.state.recent_keys.recorder(&session_name);
+        let samples = samples.inspect(move |sample| recorder.record(sample.key_expr().as_str()));
+
+        if detect_gaps {
+            let iter = GapDetector {
+                samples,
+                sources: HashMap::new(),
+                done: false,
+                span,
+            }
+            .chain(until_error.report(span))
+            .chain(dropped.report(span))
+            .chain(closed.report(span));
+
+            // The channel already stops on interruption, the summary must outlive it
+            Ok(ListStream::new(iter, call.head, Signals::empty()).into())
+        } else {
+            let iter = samples
+                .map(move |sample| conv::sample_to_record_value(sample, span))
+                .chain(until_error.report(span))
+                .chain(dropped.report(span))
+                .chain(closed.report(span));
+
+            Ok(ListStream::new(iter, call.head, engine_state.signals().clone()).into())
+        }
+    }
+}
+
+/// Error of the --until closure, which ends the stream.
+#[derive(Clone, Default)]
+struct UntilError(Arc<Mutex<Option<ShellError>>>);
+
+impl UntilError {
+    /// Yields the error of the --until closure once polled, if it failed.
+    fn report(self, span: Span) -> impl Iterator<Item = Value> {
+        std::iter::once(self)
+            .filter_map(|error| error.0.lock().unwrap().take())
+            .map(move |err| Value::error(err, span))
+    }
+}
+
+/// Reads the --count, --duration, --idle-timeout and --until flags.
+fn stop_conditions(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<(StopConditions<Sample>, UntilError), ShellError> {
+    let span = call.head;
+
+    let count = call
+        .get_flag::<i64>(engine_state, stack, "count")?
+        .map(|count| {
+            usize::try_from(count).map_err(|_| {
+                LabeledError::new("Invalid count")
+                    .with_label("Must be a non-negative integer", span)
+            })
+        })
+        .transpose()?;
+
+    let mut duration_flag = |name: &str| -> Result<Option<Duration>, ShellError> {
+        match call.get_flag::<Value>(engine_state, stack, name)? {
+            Some(v) => Ok(Some(Duration::from_nanos(v.as_duration()? as u64))),
+            None => Ok(None),
+        }
+    };
+    let duration = duration_flag("duration")?;
+    let idle_timeout = duration_flag("idle-timeout")?;
+
+    let until_error = UntilError::default();
+    let until = call
+        .get_flag::<Closure>(engine_state, stack, "until")?
+        .map(|closure| {
+            let mut closure = ClosureEval::new(engine_state, stack, closure);
+            let until_error = until_error.clone();
+            Box::new(move |sample: &Sample| {
+                match closure
+                    .run_with_value(conv::sample_to_record_value(sample.clone(), span))
+                    .and_then(|data| data.into_value(span))
+                {
+                    Ok(value) => value.is_true(),
+                    Err(err) => {
+                        *until_error.0.lock().unwrap() = Some(err);
+                        true
+                    }
+                }
+            }) as Box<dyn FnMut(&Sample) -> bool + Send>
+        });
+
+    Ok((
+        StopConditions {
+            count,
+            duration,
+            idle_timeout,
+            until,
+        },
+        until_error,
+    ))
+}
+
+/// Sequence number bookkeeping of a single source.
+#[derive(Default)]
+struct SourceStats {
+    last_sn: u32,
+    received: u64,
+    missing: u64,
+    duplicates: u64,
+    reordered: u64,
+    restarts: u64,
+    /// Missing sequence numbers that may still arrive, within [`SourceStats::WINDOW`].
+    outstanding: BTreeSet<u32>,
+}
+
+/// What a sequence number tells about its source.
+#[derive(Debug, PartialEq, Eq)]
+enum Observation {
+    /// Received in order, after `gap` missing sequence numbers.
+    InOrder { gap: u64 },
+    /// Received again.
+    Duplicate,
+    /// Received late, filling a gap.
+    Reordered,
+    /// The source started over, e.g. the publisher was restarted.
+    Restart,
+}
+
+impl SourceStats {
+    /// How far behind the last sequence number missing ones are still expected. Going back to 0
+    /// or further than this is a restart.
+    const WINDOW: u32 = 1024;
+
+    fn new(sn: u32) -> Self {
+        Self {
+            last_sn: sn,
+            received: 1,
+            ..Default::default()
+        }
+    }
+
+    fn observe(&mut self, sn: u32) -> Observation {
+        self.received += 1;
+
+        if sn > self.last_sn {
+            let gap = sn - self.last_sn - 1;
+            self.missing += gap as u64;
+            self.outstanding
+                .extend(sn.saturating_sub(Self::WINDOW).max(self.last_sn + 1)..sn);
+            self.last_sn = sn;
+            self.outstanding = self.outstanding.split_off(&sn.saturating_sub(Self::WINDOW));
+            Observation::InOrder { gap: gap as u64 }
+        } else if self.outstanding.remove(&sn) {
+            self.reordered += 1;
+            self.missing -= 1;
+            Observation::Reordered
+        } else if sn == self.last_sn || (sn != 0 && self.last_sn - sn <= Self::WINDOW) {
+            self.duplicates += 1;
+            Observation::Duplicate
+        } else {
+            // Losses before the restart remain counted
+            self.restarts += 1;
+            self.last_sn = sn;
+            self.outstanding.clear();
+            Observation::Restart
+        }
+    }
+}
+
+/// Annotates samples with sequence anomalies and ends with a per-source summary.
+struct GapDetector<I> {
+    samples: I,
+    sources: HashMap<(ZenohId, u32), SourceStats>,
+    done: bool,
+    span: Span,
+}
+
+impl<I> GapDetector<I> {
+    fn annotate(&mut self, sample: Sample) -> Value {
+        let span = self.span;
+
+        let source = sample
+            .source_info()
+            .source_id()
+            .map(|id| (id.zid(), id.eid()))
+            .zip(sample.source_info().source_sn());
+
+        let [gap, duplicate, reordered, restart] = match source {
+            None => [(); 4].map(|_| Value::nothing(span)),
+            Some((key, sn)) => {
+                let observation = match self.sources.get_mut(&key) {
+                    None => {
+                        self.sources.insert(key, SourceStats::new(sn));
+                        Observation::InOrder { gap: 0 }
+                    }
+                    Some(stats) => stats.observe(sn),
+                };
+
+                let gap = match observation {
+                    Observation::InOrder { gap } => gap,
+                    _ => 0,
+                };
+                [
+                    (gap as i64).into_value(span),
+                    (observation == Observation::Duplicate).into_value(span),
+                    (observation == Observation::Reordered).into_value(span),
+                    (observation == Observation::Restart).into_value(span),
+                ]
+            }
+        };
+
+        let mut record = match conv::sample_to_record_value(sample, span).into_record() {
+            Ok(record) => record,
+            Err(err) => return Value::error(err, span),
+        };
+        record.push("gap", gap);
+        record.push("duplicate", duplicate);
+        record.push("reordered", reordered);
+        record.push("restart", restart);
+        record.into_value(span)
+    }
+
+    fn summary(&self) -> Value {
+        let span = self.span;
+
+        let sources = self
+            .sources
+            .iter()
+            .map(|((zid, eid), stats)| {
+                record!(
+                    "source_id" => record!(
+                        "zid" => zid.to_string().into_value(span),
+                        "eid" => (*eid).into_value(span),
+                    ).into_value(span),
+                    "last_sn" => stats.last_sn.into_value(span),
+                    "received" => (stats.received as i64).into_value(span),
+                    "missing" => (stats.missing as i64).into_value(span),
+                    "duplicates" => (stats.duplicates as i64).into_value(span),
+                    "reordered" => (stats.reordered as i64).into_value(span),
+                    "restarts" => (stats.restarts as i64).into_value(span),
+                )
+                .into_value(span)
+            })
+            .collect::<Vec<_>>();
+
+        record!(
+            "summary" => true.into_value(span),
+            "sources" => Value::list(sources, span),
+        )
+        .into_value(span)
+    }
+}
+
+impl<I: Iterator<Item = Sample>> Iterator for GapDetector<I> {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.samples.next() {
+            Some(sample) => Some(self.annotate(sample)),
+            None => {
+                self.done = true;
+                Some(self.summary())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Observation, SourceStats};
+
+    fn observe_all(sns: &[u32]) -> (SourceStats, Vec<Observation>) {
+        let mut stats = SourceStats::new(sns[0]);
+        let observations = sns[1..].iter().map(|&sn| stats.observe(sn)).collect();
+        (stats, observations)
+    }
+
+    #[test]
+    fn gap() {
+        let (stats, observations) = observe_all(&[0, 1, 4, 5]);
+        assert_eq!(
+            observations,
+            [
+                Observation::InOrder { gap: 0 },
+                Observation::InOrder { gap: 2 },
+                Observation::InOrder { gap: 0 },
+            ]
+        );
+        assert_eq!(stats.missing, 2);
+        assert_eq!(stats.received, 4);
+    }
+
+    #[test]
+    fn duplicate() {
+        let (stats, observations) = observe_all(&[0, 1, 2, 2, 1]);
+        assert_eq!(
+            observations[2..],
+            [Observation::Duplicate, Observation::Duplicate]
+        );
+        assert_eq!(stats.duplicates, 2);
+        assert_eq!(stats.reordered, 0);
+        assert_eq!(stats.missing, 0);
+    }
+
+    #[test]
+    fn late_fill() {
+        let (stats, observations) = observe_all(&[0, 3, 1, 1, 2]);
+        assert_eq!(
+            observations,
+            [
+                Observation::InOrder { gap: 2 },
+                Observation::Reordered,
+                Observation::Duplicate,
+                Observation::Reordered,
+            ]
+        );
+        assert_eq!(stats.missing, 0);
+        assert_eq!(stats.reordered, 2);
+        assert_eq!(stats.duplicates, 1);
+    }
+
+    #[test]
+    fn restart() {
+        let (stats, observations) = observe_all(&[0, 1, 3, 0, 1, 2]);
+        assert_eq!(
+            observations,
+            [
+                Observation::InOrder { gap: 0 },
+                Observation::InOrder { gap: 1 },
+                Observation::Restart,
+                Observation::InOrder { gap: 0 },
+                Observation::InOrder { gap: 0 },
+            ]
+        );
+        assert_eq!(stats.restarts, 1);
+        assert_eq!(stats.missing, 1);
+        assert_eq!(stats.reordered, 0);
+    }
+
+    #[test]
+    fn restart_beyond_window() {
+        let far = SourceStats::WINDOW + 10;
+        let (stats, observations) = observe_all(&[far, far + 1, 5]);
+        assert_eq!(observations[1], Observation::Restart);
+        assert_eq!(stats.last_sn, 5);
+    }
 
-        Ok(ListStream::new(iter, call.head, engine_state.signals().clone()).into())
+    #[test]
+    fn outstanding_bounded_by_window() {
+        let (stats, _) = observe_all(&[0, 10 * SourceStats::WINDOW]);
+        assert_eq!(stats.outstanding.len(), SourceStats::WINDOW as usize);
+        assert_eq!(stats.missing, (10 * SourceStats::WINDOW - 1) as u64);
     }
 }
@@ -0,0 +1,385 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    collections::BTreeMap,
+    io::{IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, IntoValue, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+use zenoh::{
+    sample::{Sample, SampleKind},
+    Session, Wait,
+};
+
+use crate::{call_ext2::CallExt2, completion, conv, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct Watch {
+    state: State,
+}
+
+impl Watch {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Watch {
+    fn name(&self) -> &str {
+        "zenoh watch"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .session()
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::list(Type::record()))
+            .keyexpr()
+            .allowed_origin()
+            .named(
+                "refresh",
+                SyntaxShape::Duration,
+                "Redraw interval (defaults to 1sec)",
+                Some('r'),
+            )
+            .named(
+                "duration",
+                SyntaxShape::Duration,
+                "Stop watching after this duration (defaults to until interrupted)",
+                Some('d'),
+            )
+            .switch(
+                "no-redraw",
+                "Don't draw the live table, only return the final one",
+                None,
+            )
+    }
+
+    fn description(&self) -> &str {
+        "Watch the latest value of each key matching a key-expression"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Keeps one row per key with its latest value, update count, update rate and age. \
+        The table is redrawn in place on stderr while watching, and returned once the command \
+        stops so it can be inspected further (e.g. with 'explore'). Deleted keys are marked \
+        as removed."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        const WATCH_CHANNEL_SIZE: usize = 256;
+        const DEFAULT_REFRESH: Duration = Duration::from_secs(1);
+        // Granularity at which interruptions are checked
+        const TICK: Duration = Duration::from_millis(50);
+
+        let span = call.head;
+
+        let keyexpr = call.req::<String>(engine_state, stack, 0)?;
+        let refresh = call
+            .get_flag::<Value>(engine_state, stack, "refresh")?
+            .map(|v| v.as_duration().map(|ns| Duration::from_nanos(ns as u64)))
+            .transpose()?
+            .unwrap_or(DEFAULT_REFRESH);
+        let deadline = call
+            .get_flag::<Value>(engine_state, stack, "duration")?
+            .map(|v| {
+                v.as_duration()
+                    .map(|ns| Instant::now() + Duration::from_nanos(ns as u64))
+            })
+            .transpose()?;
+        let redraw =
+            !call.has_flag(engine_state, stack, "no-redraw")? && std::io::stderr().is_terminal();
+
+        if refresh.is_zero() {
+            return Err(LabeledError::new("Invalid refresh interval")
+                .with_label("Must be greater than zero", span)
+                .into());
+        }
+
+        let (tx, rx) = flume::bounded(WATCH_CHANNEL_SIZE);
+
+        let session_name = call.session(engine_state, stack)?;
+        let session = self.state.with_session(&session_name, Session::clone)?;
+
+        let _sub = self
+            .state
+            .with_session(&session_name, move |sess| {
+                let mut sub = sess.declare_subscriber(keyexpr).callback(move |sample| {
+                    let _ = tx.send(sample);
+                });
+
+                if let Some(origin) = call.allowed_origin(engine_state, stack)? {
+                    sub = sub.allowed_origin(origin);
+                }
+                sub.wait()
+            })?
+            .map_err(|e| {
+                LabeledError::new("Subscriber declaration failed")
+                    .with_label(format!("Zenoh subscriber failed: {e}"), span)
+            })?;
+
+        let signals = engine_state.signals();
+        let mut table = Table::default();
+        let mut screen = Screen::default();
+        let mut next_refresh = Instant::now() + refresh;
+
+        loop {
+            let now = Instant::now();
+
+            if signals.interrupted() || deadline.is_some_and(|deadline| now >= deadline) {
+                break;
+            }
+
+            if session.is_closed() {
+                screen.clear();
+                return Err(LabeledError::new("Session closed")
+                    .with_label("The session was closed while watching", span)
+                    .into());
+            }
+
+            if now >= next_refresh {
+                table.tick(now);
+                if redraw {
+                    screen.draw(&table.render(now));
+                }
+                next_refresh = now + refresh;
+            }
+
+            let wakeup = deadline.map_or(next_refresh, |deadline| deadline.min(next_refresh));
+            match rx.recv_deadline(wakeup.min(now + TICK)) {
+                Ok(sample) => table.update(sample, span),
+                Err(flume::RecvTimeoutError::Timeout) => continue,
+                // The next iteration reports whether the session was closed
+                Err(flume::RecvTimeoutError::Disconnected) if !session.is_closed() => break,
+                Err(flume::RecvTimeoutError::Disconnected) => continue,
+            }
+        }
+
+        screen.clear();
+
+        Ok(PipelineData::Value(
+            table.into_value(Instant::now(), span),
+            None,
+        ))
+    }
+}
+
+/// Latest state of a watched key.
+struct Row {
+    value: Value,
+    display: String,
+    count: u64,
+    window: u64,
+    rate: f64,
+    updated: Instant,
+    removed: bool,
+}
+
+/// Watched keys sorted by key-expression.
+#[derive(Default)]
+struct Table {
+    rows: BTreeMap<String, Row>,
+    window_start: Option<Instant>,
+}
+
+impl Table {
+    /// Maximum number of rows in the live view.
+    const MAX_ROWS: usize = 40;
+
+    /// Maximum width of a value in the live view.
+    const MAX_VALUE_WIDTH: usize = 40;
+
+    fn update(&mut self, sample: Sample, span: Span) {
+        let now = Instant::now();
+        self.window_start.get_or_insert(now);
+
+        let (value, display, removed) = match sample.kind() {
+            SampleKind::Put => {
+                let display = match sample.payload().try_to_string() {
+                    Ok(s) => s.replace(['\n', '\r'], " "),
+                    Err(_) => format!("<{} bytes>", sample.payload().len()),
+                };
+                (conv::bytes_to_value(sample.payload(), span), display, false)
+            }
+            SampleKind::Delete => (Value::nothing(span), String::new(), true),
+        };
+
+        let row = self
+            .rows
+            .entry(sample.key_expr().to_string())
+            .or_insert_with(|| Row {
+                value: Value::nothing(span),
+                display: String::new(),
+                count: 0,
+                window: 0,
+                rate: 0.0,
+                updated: now,
+                removed: false,
+            });
+
+        // Keep the last known value of removed keys
+        if !removed {
+            row.value = value;
+            row.display = display;
+        }
+        row.removed = removed;
+        row.count += 1;
+        row.window += 1;
+        row.updated = now;
+    }
+
+    /// Computes update rates over the elapsed window and starts a new one.
+    fn tick(&mut self, now: Instant) {
+        let Some(start) = self.window_start.replace(now) else {
+            return;
+        };
+
+        let secs = now.duration_since(start).as_secs_f64();
+        if secs > 0.0 {
+            for row in self.rows.values_mut() {
+                row.rate = row.window as f64 / secs;
+                row.window = 0;
+            }
+        }
+    }
+
+    fn render(&self, now: Instant) -> Vec<String> {
+        let mut lines = vec![];
+
+        let cells = self
+            .rows
+            .iter()
+            .take(Self::MAX_ROWS)
+            .map(|(key, row)| {
+                let value = if row.display.chars().count() > Self::MAX_VALUE_WIDTH {
+                    let truncated = row
+                        .display
+                        .chars()
+                        .take(Self::MAX_VALUE_WIDTH - 1)
+                        .collect::<String>();
+                    format!("{truncated}…")
+                } else {
+                    row.display.clone()
+                };
+
+                [
+                    key.clone(),
+                    value,
+                    row.count.to_string(),
+                    format!("{:.1}/s", row.rate),
+                    format!("{:.1}s", now.duration_since(row.updated).as_secs_f64()),
+                    if row.removed { "yes" } else { "" }.to_string(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let header = ["keyexpr", "value", "count", "rate", "age", "removed"].map(String::from);
+
+        let mut widths = header.clone().map(|h| h.chars().count());
+        for row in cells.iter() {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let format_row = |row: &[String; 6]| {
+            row.iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        lines.push(format_row(&header));
+        lines.extend(cells.iter().map(format_row));
+
+        if self.rows.len() > Self::MAX_ROWS {
+            lines.push(format!("… {} more", self.rows.len() - Self::MAX_ROWS));
+        }
+
+        lines
+    }
+
+    fn into_value(self, now: Instant, span: Span) -> Value {
+        let rows = self
+            .rows
+            .into_iter()
+            .map(|(key, row)| {
+                record!(
+                    "keyexpr" => key.into_value(span),
+                    "value" => row.value,
+                    "count" => (row.count as i64).into_value(span),
+                    "rate" => row.rate.into_value(span),
+                    "age" => Value::duration(now.duration_since(row.updated).as_nanos() as i64, span),
+                    "removed" => row.removed.into_value(span),
+                )
+                .into_value(span)
+            })
+            .collect::<Vec<_>>();
+
+        Value::list(rows, span)
+    }
+}
+
+/// Live view drawn in place on stderr.
+#[derive(Default)]
+struct Screen {
+    lines: usize,
+}
+
+impl Screen {
+    /// Draws the view with line wrapping disabled, so that rows wider than the terminal are cut
+    /// at its edge instead of taking more lines than [`Screen::rewind`] moves back over.
+    fn draw(&mut self, lines: &[String]) {
+        let mut stderr = std::io::stderr().lock();
+        self.rewind(&mut stderr);
+        let _ = write!(stderr, "\x1b[?7l");
+        for line in lines {
+            let _ = writeln!(stderr, "{line}");
+        }
+        let _ = write!(stderr, "\x1b[?7h");
+        let _ = stderr.flush();
+        self.lines = lines.len();
+    }
+
+    fn clear(&mut self) {
+        let mut stderr = std::io::stderr().lock();
+        self.rewind(&mut stderr);
+        let _ = stderr.flush();
+        self.lines = 0;
+    }
+
+    /// Moves the cursor back to the first line of the view and clears everything below.
+    fn rewind(&self, w: &mut impl Write) {
+        if self.lines > 0 {
+            let _ = write!(w, "\x1b[{}A\x1b[J", self.lines);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use nu_engine::{CallExt, ClosureEval};
+use nu_protocol::{
+    engine::{Call, Closure, Command, EngineState, Stack},
+    LabeledError, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type, Value,
+};
+
+use crate::{completion, signature_ext::SignatureExt, State};
+
+#[derive(Clone)]
+pub(crate) struct WithSession {
+    state: State,
+}
+
+impl WithSession {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for WithSession {
+    fn name(&self) -> &str {
+        "zenoh with-session"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("session", SyntaxShape::String, "Session name")
+            .required(
+                "block",
+                SyntaxShape::Closure(None),
+                "Block to run with the session used by default",
+            )
+            .zenoh_category()
+            .input_output_type(Type::Any, Type::Any)
+    }
+
+    fn description(&self) -> &str {
+        "Run a block with a session used by default"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Sets $env.ZENOH_SESSION for the duration of the block, which commands use when no \
+        --session is given."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let session_name = call.req::<Spanned<String>>(engine_state, stack, 0)?;
+        let block = call.req::<Closure>(engine_state, stack, 1)?;
+
+        if !self
+            .state
+            .sessions
+            .read()
+            .unwrap()
+            .contains_key(&session_name.item)
+        {
+            return Err(LabeledError::new("Session not found")
+                .with_label(
+                    format!("No session named '{}'", session_name.item),
+                    session_name.span,
+                )
+                .into());
+        }
+
+        let mut closure = ClosureEval::new(engine_state, stack, block);
+        closure.add_env_var(
+            State::SESSION_ENV_VAR.to_string(),
+            Value::string(session_name.item, session_name.span),
+        );
+        closure.run_with_input(input)
+    }
+}
@@ -0,0 +1,323 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    record, LabeledError, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
+    Value,
+};
+use zenoh::{internal::runtime::Runtime, session, Session, Wait};
+
+use crate::{
+    cmd::{
+        runtime::{close_runtime, open::start_runtime},
+        workspace::VERSION,
+    },
+    completion,
+    session_meta::SessionSource,
+    signature_ext::SignatureExt,
+    State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Load {
+    state: State,
+}
+
+impl Load {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Load {
+    fn name(&self) -> &str {
+        "zenoh workspace load"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::table())
+            .required("file", SyntaxShape::Filepath, "Workspace file")
+    }
+
+    fn description(&self) -> &str {
+        "Restore the runtimes, sessions and declarations saved by 'zenoh workspace save'"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Runtimes and sessions with the same name as saved ones are closed and replaced, along \
+        with the sessions attached to a replaced runtime. \
+        Liveliness tokens, subscribers and queryables are declared again in the background and \
+        kept until their session is closed: subscribers only feed key-expression completion, and \
+        queryables accept queries without replying to them. Storages are restored with the \
+        configuration of their runtime. Returns whether each entry was restored."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let path = call.req::<Spanned<PathBuf>>(engine_state, stack, 0)?;
+
+        let invalid = |msg: String| {
+            ShellError::from(LabeledError::new("Invalid workspace file").with_label(msg, path.span))
+        };
+
+        let contents = std::fs::read_to_string(&path.item)
+            .map_err(|e| invalid(format!("Could not read {}: {e}", path.item.display())))?;
+        let workspace = nu_json::from_str::<nu_json::Value>(&contents)
+            .map_err(|e| invalid(format!("Could not parse {}: {e}", path.item.display())))?;
+
+        match workspace.find("version").and_then(nu_json::Value::as_u64) {
+            Some(VERSION) => {}
+            Some(version) => return Err(invalid(format!("Unsupported version {version}"))),
+            None => return Err(invalid("Missing version".to_string())),
+        }
+
+        let mut rows = vec![];
+
+        for entry in entries(&workspace, "runtimes") {
+            let name = field(entry, "name");
+            let result = name
+                .as_deref()
+                .map_err(Clone::clone)
+                .and_then(|name| self.load_runtime(name, entry, span));
+            rows.push(row("runtime", name.ok(), None, result, span));
+        }
+
+        for entry in entries(&workspace, "sessions") {
+            let name = field(entry, "name");
+            let result = name
+                .as_deref()
+                .map_err(Clone::clone)
+                .and_then(|name| self.load_session(name, entry));
+            rows.push(row("session", name.ok(), None, result, span));
+        }
+
+        for entry in entries(&workspace, "declarations") {
+            let kind = field(entry, "kind").unwrap_or_default();
+            let keyexpr = field(entry, "keyexpr");
+            let session = field(entry, "session");
+            let result = match (&session, &keyexpr) {
+                (Ok(session), Ok(keyexpr)) => self.load_declaration(session, &kind, keyexpr),
+                (Err(err), _) | (_, Err(err)) => Err(err.clone()),
+            };
+            let kind = if kind.is_empty() {
+                "declaration".to_string()
+            } else {
+                kind
+            };
+            rows.push(row(&kind, keyexpr.ok(), session.ok(), result, span));
+        }
+
+        Ok(PipelineData::Value(Value::list(rows, span), None))
+    }
+}
+
+impl Load {
+    fn load_runtime(&self, name: &str, entry: &nu_json::Value, span: Span) -> Result<(), String> {
+        let config = config(entry)?;
+        close_runtime(&self.state, name, true, span).map_err(describe)?;
+
+        let runtime = start_runtime(config, span).map_err(describe)?;
+        self.state
+            .runtimes
+            .write()
+            .unwrap()
+            .insert(name.to_string(), runtime);
+
+        Ok(())
+    }
+
+    fn load_session(&self, name: &str, entry: &nu_json::Value) -> Result<(), String> {
+        let source = match field(entry, "source")?.as_str() {
+            "config-file" => SessionSource::ConfigFile(field(entry, "config_file")?.into()),
+            "record" => SessionSource::Record,
+            "scout" => SessionSource::Scout,
+            "runtime" => SessionSource::Runtime(field(entry, "runtime")?),
+            _ => SessionSource::Default,
+        };
+
+        // Resolved before closing the previous session, which is kept if this fails.
+        let target = match &source {
+            SessionSource::Runtime(runtime_name) => Target::Runtime(
+                self.state
+                    .runtimes
+                    .read()
+                    .unwrap()
+                    .get(runtime_name)
+                    .cloned()
+                    .ok_or_else(|| format!("Runtime '{runtime_name}' was not found"))?,
+            ),
+            _ => Target::Config(config(entry)?),
+        };
+
+        // The previous session is closed first, so that it releases the endpoints it listens on.
+        let previous = self.state.sessions.write().unwrap().remove(name);
+        let replaced = previous.is_some();
+        if let Some(previous) = previous {
+            self.state.session_closed(name);
+            previous
+                .close()
+                .wait()
+                .map_err(|e| format!("Could not close the previous session: {e}"))?;
+        }
+
+        let session = match target {
+            Target::Config(config) => zenoh::open(config).wait(),
+            Target::Runtime(runtime) => session::init(runtime).wait(),
+        }
+        .map_err(|e| {
+            let lost = if replaced {
+                " (the previous session was closed)"
+            } else {
+                ""
+            };
+            format!("Could not establish Zenoh session{lost}: {e}")
+        })?;
+
+        self.state
+            .sessions
+            .write()
+            .unwrap()
+            .insert(name.to_string(), session);
+        self.state.session_opened(name, source);
+
+        Ok(())
+    }
+
+    fn load_declaration(
+        &self,
+        session_name: &str,
+        kind: &str,
+        keyexpr: &str,
+    ) -> Result<(), String> {
+        let session = self
+            .state
+            .with_session(session_name, Session::clone)
+            .map_err(describe)?;
+
+        let keyexpr = keyexpr.to_string();
+        let declarations = &self.state.declarations;
+        let declaration = match kind {
+            "token" => declarations.declare(session_name, &session, "token", keyexpr.clone(), {
+                move |sess| sess.liveliness().declare_token(&keyexpr).wait()
+            }),
+            "subscriber" => {
+                let recorder = Arc::new(Mutex::new(self.state.recent_keys.recorder(session_name)));
+                declarations.declare(session_name, &session, "subscriber", keyexpr.clone(), {
+                    move |sess| {
+                        let recorder = recorder.clone();
+                        sess.declare_subscriber(&keyexpr)
+                            .callback(move |sample| {
+                                recorder.lock().unwrap().record(sample.key_expr().as_str())
+                            })
+                            .wait()
+                    }
+                })
+            }
+            // Dropping a query sends its final reply
+            "queryable" => {
+                declarations.declare(session_name, &session, "queryable", keyexpr.clone(), {
+                    move |sess| sess.declare_queryable(&keyexpr).callback(drop).wait()
+                })
+            }
+            "" => return Err("Missing 'kind'".to_string()),
+            kind => return Err(format!("Unknown declaration kind '{kind}'")),
+        }
+        .map_err(|e| format!("Zenoh {kind} declaration failed: {e}"))?;
+        declarations.pin(session_name, declaration);
+
+        Ok(())
+    }
+}
+
+/// What a session is opened with.
+enum Target {
+    Config(zenoh::Config),
+    Runtime(Runtime),
+}
+
+fn entries<'a>(
+    workspace: &'a nu_json::Value,
+    key: &str,
+) -> impl Iterator<Item = &'a nu_json::Value> {
+    workspace
+        .find(key)
+        .and_then(nu_json::Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+fn field(entry: &nu_json::Value, key: &str) -> Result<String, String> {
+    entry
+        .find(key)
+        .and_then(nu_json::Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| format!("Missing '{key}'"))
+}
+
+fn config(entry: &nu_json::Value) -> Result<zenoh::Config, String> {
+    let config = entry
+        .find("config")
+        .filter(|config| config.is_object())
+        .ok_or_else(|| "Missing 'config'".to_string())?;
+
+    zenoh::Config::from_json5(&config.to_string())
+        .map_err(|e| format!("Could not parse config: {e}"))
+}
+
+/// Flattens an error into its message followed by its labels, which hold the actual cause.
+fn describe(err: LabeledError) -> String {
+    std::iter::once(err.msg.as_str())
+        .chain(err.labels.iter().map(|label| label.text.as_str()))
+        .collect::<Vec<_>>()
+        .join(": ")
+}
+
+fn row(
+    kind: &str,
+    name: Option<String>,
+    session: Option<String>,
+    result: Result<(), String>,
+    span: Span,
+) -> Value {
+    let string = |value: Option<String>| {
+        value.map_or(Value::nothing(span), |value| Value::string(value, span))
+    };
+
+    Value::record(
+        record! {
+            "kind" => Value::string(kind, span),
+            "name" => string(name),
+            "session" => string(session),
+            "restored" => Value::bool(result.is_ok(), span),
+            "error" => string(result.err()),
+        },
+        span,
+    )
+}
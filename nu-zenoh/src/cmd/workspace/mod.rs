@@ -0,0 +1,59 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+//! Workspace files hold the runtimes, sessions and declarations of a shell as JSON:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "runtimes": [{ "name": "r", "config": { ... } }],
+//!   "sessions": [{ "name": "s", "source": "record", "config_file": null, "runtime": null, "config": { ... } }],
+//!   "declarations": [{ "session": "s", "kind": "token", "keyexpr": "a/b" }]
+//! }
+//! ```
+//!
+//! Configurations are saved without their id, so that loaded runtimes and sessions get their own.
+//! Storages are configured by the storage manager plugin of a runtime, so they are saved with its
+//! configuration.
+//!
+//! Declarations are liveliness tokens, subscribers and queryables. The 'zenoh sub' and
+//! 'zenoh reply' streams consuming subscribers and queryables can't be saved, so they are
+//! restored in the background.
+
+pub(crate) mod load;
+pub(crate) mod save;
+
+const VERSION: u64 = 1;
+
+/// Parses a configuration (as printed by Zenoh) into JSON, without its id.
+fn config_to_json(config: &str) -> nu_json::Value {
+    let mut config = nu_json::from_str::<nu_json::Value>(config).unwrap_or(nu_json::Value::Null);
+    if let nu_json::Value::Object(config) = &mut config {
+        config.remove("id");
+    }
+    config
+}
+
+fn json_object<'a>(entries: impl IntoIterator<Item = (&'a str, nu_json::Value)>) -> nu_json::Value {
+    let mut object = nu_json::Map::new();
+    for (key, value) in entries {
+        object.insert(key.to_string(), value);
+    }
+    nu_json::Value::Object(object)
+}
+
+fn json_string(value: Option<&str>) -> nu_json::Value {
+    value.map_or(nu_json::Value::Null, |value| {
+        nu_json::Value::String(value.to_string())
+    })
+}
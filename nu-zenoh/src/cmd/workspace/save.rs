@@ -0,0 +1,172 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::path::PathBuf;
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    engine::{Call, Command, EngineState, Stack},
+    LabeledError, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type, Value,
+};
+
+use crate::{
+    cmd::workspace::{config_to_json, json_object, json_string, VERSION},
+    completion,
+    session_meta::SessionSource,
+    signature_ext::SignatureExt,
+    State,
+};
+
+#[derive(Clone)]
+pub(crate) struct Save {
+    state: State,
+}
+
+impl Save {
+    pub(crate) fn new(state: State) -> Self {
+        Self { state }
+    }
+}
+
+impl Command for Save {
+    fn name(&self) -> &str {
+        "zenoh workspace save"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .zenoh_category()
+            .input_output_type(Type::Nothing, Type::Nothing)
+            .required("file", SyntaxShape::Filepath, "Workspace file")
+            .switch("force", "Overwrite the file if it exists", Some('f'))
+    }
+
+    fn description(&self) -> &str {
+        "Save the runtimes, sessions and declarations of the shell to a file"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Sessions are saved with their current configuration, and runtimes with theirs, including \
+        the storages of their plugins. Declarations are the liveliness tokens, subscribers and \
+        queryables tracked for 'zenoh session reopen'. Use 'zenoh workspace load' to restore \
+        them."
+    }
+
+    completion::dynamic_completion!();
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path = call.req::<Spanned<PathBuf>>(engine_state, stack, 0)?;
+        let force = call.has_flag(engine_state, stack, "force")?;
+
+        if path.item.exists() && !force {
+            return Err(LabeledError::new("File already exists")
+                .with_label("Use --force to overwrite it", path.span)
+                .into());
+        }
+
+        std::fs::write(&path.item, self.workspace().to_string()).map_err(|e| {
+            LabeledError::new("Failed to save workspace").with_label(
+                format!("Could not write {}: {e}", path.item.display()),
+                path.span,
+            )
+        })?;
+
+        Ok(PipelineData::Value(Value::nothing(call.head), None))
+    }
+}
+
+impl Save {
+    fn workspace(&self) -> nu_json::Value {
+        // Sessions are locked before runtimes, so the runtimes are released first
+        let runtimes = {
+            let runtimes = self.state.runtimes.read().unwrap();
+            let mut runtime_names = runtimes.keys().collect::<Vec<_>>();
+            runtime_names.sort();
+
+            runtime_names
+                .into_iter()
+                .map(|name| {
+                    json_object([
+                        ("name", json_string(Some(name))),
+                        (
+                            "config",
+                            config_to_json(&runtimes[name].config().lock().to_string()),
+                        ),
+                    ])
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let sessions = self.state.sessions.read().unwrap();
+        let session_meta = self.state.session_meta.read().unwrap();
+        let mut session_names = sessions.keys().collect::<Vec<_>>();
+        session_names.sort();
+
+        let sessions = session_names
+            .into_iter()
+            .map(|name| {
+                let source = session_meta
+                    .get(name)
+                    .map_or(SessionSource::Default, |meta| meta.source.clone());
+                let config_file = match &source {
+                    SessionSource::ConfigFile(path) => Some(path.display().to_string()),
+                    _ => None,
+                };
+                let (runtime, config) = match &source {
+                    SessionSource::Runtime(runtime) => {
+                        (Some(runtime.as_str()), nu_json::Value::Null)
+                    }
+                    _ => (
+                        None,
+                        config_to_json(&sessions[name].config().lock().to_string()),
+                    ),
+                };
+
+                json_object([
+                    ("name", json_string(Some(name))),
+                    ("source", json_string(Some(source.kind()))),
+                    ("config_file", json_string(config_file.as_deref())),
+                    ("runtime", json_string(runtime)),
+                    ("config", config),
+                ])
+            })
+            .collect();
+
+        let declarations = self
+            .state
+            .declarations
+            .all()
+            .into_iter()
+            .map(|(session, declaration)| {
+                json_object([
+                    ("session", json_string(Some(&session))),
+                    ("kind", json_string(Some(declaration.kind()))),
+                    ("keyexpr", json_string(Some(declaration.keyexpr()))),
+                ])
+            })
+            .collect();
+
+        json_object([
+            ("version", nu_json::Value::U64(VERSION)),
+            ("runtimes", nu_json::Value::Array(runtimes)),
+            ("sessions", nu_json::Value::Array(sessions)),
+            ("declarations", nu_json::Value::Array(declarations)),
+        ])
+    }
+}
@@ -16,7 +16,7 @@ use nu_protocol::{
     IntoValue, PipelineData, ShellError, Signature, Type,
 };
 
-use crate::{call_ext2::CallExt2, signature_ext::SignatureExt, State};
+use crate::{call_ext2::CallExt2, completion, signature_ext::SignatureExt, State};
 
 #[derive(Clone)]
 pub(crate) struct Zid {
@@ -45,6 +45,8 @@ impl Command for Zid {
         "Session ZID"
     }
 
+    completion::dynamic_completion!();
+
     fn run(
         &self,
         engine_state: &EngineState,
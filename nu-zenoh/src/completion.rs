@@ -0,0 +1,319 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{mpsc, Arc, Mutex, RwLock},
+    time::{Duration, Instant},
+};
+
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast,
+    engine::{ArgType, Command, EngineState, Stack},
+    DynamicCompletionCallRef, DynamicSuggestion, ShellError,
+};
+use zenoh::{Session, Wait};
+
+use crate::{call_ext2, State};
+
+/// Keys seen by `zenoh sub` and `zenoh get`, most recently seen first, per session name.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RecentKeys(Arc<Mutex<HashMap<String, KeyRing>>>);
+
+#[derive(Debug, Default)]
+struct KeyRing {
+    keys: VecDeque<String>,
+    seen: HashSet<String>,
+}
+
+impl RecentKeys {
+    const CAPACITY: usize = 512;
+
+    /// Returns a recorder for the keys of the samples consumed on the session named `session`.
+    pub(crate) fn recorder(&self, session: &str) -> KeyRecorder {
+        KeyRecorder {
+            keys: self.clone(),
+            session: session.to_string(),
+            last: String::new(),
+        }
+    }
+
+    pub(crate) fn forget(&self, session: &str) {
+        self.0.lock().unwrap().remove(session);
+    }
+
+    fn insert(&self, session: &str, keyexpr: &str) {
+        let mut sessions = self.0.lock().unwrap();
+        if !sessions.contains_key(session) {
+            sessions.insert(session.to_string(), KeyRing::default());
+        }
+        let ring = sessions.get_mut(session).unwrap();
+
+        if ring.seen.contains(keyexpr) {
+            if let Some(index) = ring.keys.iter().position(|key| key == keyexpr) {
+                ring.keys.remove(index);
+            }
+        } else if ring.keys.len() == Self::CAPACITY {
+            if let Some(oldest) = ring.keys.pop_back() {
+                ring.seen.remove(&oldest);
+            }
+        }
+        ring.keys.push_front(keyexpr.to_string());
+        ring.seen.insert(keyexpr.to_string());
+    }
+
+    fn get(&self, session: &str) -> Vec<String> {
+        self.0
+            .lock()
+            .unwrap()
+            .get(session)
+            .map(|ring| ring.keys.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Feeds [`RecentKeys`] from the consuming side of a stream, so that Zenoh callbacks are left
+/// alone. Runs of samples with the same key are only recorded once.
+pub(crate) struct KeyRecorder {
+    keys: RecentKeys,
+    session: String,
+    last: String,
+}
+
+impl KeyRecorder {
+    pub(crate) fn record(&mut self, keyexpr: &str) {
+        if self.last != keyexpr {
+            self.last.clear();
+            self.last.push_str(keyexpr);
+            self.keys.insert(&self.session, keyexpr);
+        }
+    }
+}
+
+/// Admin-space key-expressions per session name, as last queried.
+#[derive(Clone, Default)]
+pub(crate) struct AdminKeys(Arc<Mutex<HashMap<String, AdminEntry>>>);
+
+struct AdminEntry {
+    queried: Instant,
+    refreshing: bool,
+    keys: Vec<(String, &'static str)>,
+}
+
+impl AdminKeys {
+    /// Age after which cached keys are refreshed in the background.
+    const MAX_AGE: Duration = Duration::from_secs(5);
+
+    /// Only the first completion on a session waits for the admin space; later ones get the
+    /// cached keys right away, refreshed in the background once stale.
+    fn get(&self, session_name: &str, session: &Session) -> Vec<(String, &'static str)> {
+        let mut entries = self.0.lock().unwrap();
+        if let Some(entry) = entries.get_mut(session_name) {
+            if entry.queried.elapsed() > Self::MAX_AGE && !entry.refreshing {
+                entry.refreshing = true;
+                let admin_keys = self.clone();
+                let session_name = session_name.to_string();
+                let session = session.clone();
+                std::thread::spawn(move || {
+                    admin_keys.store(&session_name, query_admin_keys(&session));
+                });
+            }
+            return entry.keys.clone();
+        }
+        drop(entries);
+
+        let keys = query_admin_keys(session);
+        self.store(session_name, keys.clone());
+        keys
+    }
+
+    pub(crate) fn forget(&self, session: &str) {
+        self.0.lock().unwrap().remove(session);
+    }
+
+    fn store(&self, session: &str, keys: Vec<(String, &'static str)>) {
+        self.0.lock().unwrap().insert(
+            session.to_string(),
+            AdminEntry {
+                queried: Instant::now(),
+                refreshing: false,
+                keys,
+            },
+        );
+    }
+}
+
+/// Admin-space declarations offered as completions, under `@/<zid>/<whatami>/<kind>/<keyexpr>`.
+const ADMIN_KINDS: [&str; 3] = ["subscriber", "queryable", "publisher"];
+
+/// How long a query of the admin space may take, as it can block the prompt.
+const ADMIN_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// Implements `get_dynamic_completion` with [`complete`], for a command holding the shell state.
+macro_rules! dynamic_completion {
+    () => {
+        #[allow(deprecated)]
+        fn get_dynamic_completion(
+            &self,
+            engine_state: &nu_protocol::engine::EngineState,
+            stack: &mut nu_protocol::engine::Stack,
+            call: nu_protocol::DynamicCompletionCallRef,
+            arg_type: &nu_protocol::engine::ArgType,
+            _experimental: nu_protocol::engine::ExperimentalMarker,
+        ) -> Result<Option<Vec<nu_protocol::DynamicSuggestion>>, nu_protocol::ShellError> {
+            $crate::completion::complete(&self.state, self, engine_state, stack, call, arg_type)
+        }
+    };
+}
+
+pub(crate) use dynamic_completion;
+
+/// Completes an argument of a command according to its signature: 'session' and 'runtime'
+/// positionals and flags get names, 'keyexpr' positionals get key-expressions, and enum flags
+/// get their values.
+pub(crate) fn complete(
+    state: &State,
+    command: &dyn Command,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: DynamicCompletionCallRef,
+    arg_type: &ArgType,
+) -> Result<Option<Vec<DynamicSuggestion>>, ShellError> {
+    let name = match arg_type {
+        ArgType::Flag(flag) => flag.as_ref(),
+        ArgType::Positional(index) => {
+            let signature = command.signature();
+            return match signature
+                .get_positional(*index)
+                .map(|arg| arg.name.as_str())
+            {
+                Some("session") => Ok(Some(names(&state.sessions))),
+                Some("runtime") => Ok(Some(names(&state.runtimes))),
+                Some("keyexpr") => Ok(keyexpr(state, engine_state, stack, call)),
+                _ => Ok(None),
+            };
+        }
+    };
+
+    let suggestions = match name {
+        "session" => names(&state.sessions),
+        "runtime" => names(&state.runtimes),
+        flag => match call_ext2::flag_values(flag) {
+            Some(values) => values
+                .iter()
+                .map(|(value, description)| DynamicSuggestion {
+                    value: value.to_string(),
+                    description: Some(description.to_string()),
+                    ..Default::default()
+                })
+                .collect(),
+            None => return Ok(None),
+        },
+    };
+
+    Ok(Some(suggestions))
+}
+
+/// Suggests the keys recently seen on the session of the call, followed by the key-expressions
+/// of the subscribers, queryables and publishers found in the admin space.
+fn keyexpr(
+    state: &State,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: DynamicCompletionCallRef,
+) -> Option<Vec<DynamicSuggestion>> {
+    let session_name = session_name(engine_state, stack, call.call)?;
+    let session = state.with_session(&session_name, Session::clone).ok()?;
+
+    let mut seen = HashSet::new();
+    let suggestions = state
+        .recent_keys
+        .get(&session_name)
+        .into_iter()
+        .map(|keyexpr| (keyexpr, "recently seen"))
+        .chain(state.admin_keys.get(&session_name, &session))
+        .filter(|(keyexpr, _)| seen.insert(keyexpr.clone()))
+        .map(|(value, kind)| DynamicSuggestion {
+            value,
+            description: Some(kind.to_string()),
+            ..Default::default()
+        })
+        .collect();
+
+    Some(suggestions)
+}
+
+/// The session of a call being completed, read like `CallExt2::session` does from a parsed call.
+fn session_name(engine_state: &EngineState, stack: &mut Stack, call: &ast::Call) -> Option<String> {
+    if let Some(name) = call
+        .get_flag::<String>(engine_state, stack, "session")
+        .ok()?
+    {
+        return Some(name);
+    }
+
+    match stack.get_env_var(engine_state, State::SESSION_ENV_VAR) {
+        Some(value) => value.coerce_string().ok(),
+        None => Some(State::DEFAULT_SESSION_NAME.to_string()),
+    }
+}
+
+fn names<T>(map: &RwLock<HashMap<String, T>>) -> Vec<DynamicSuggestion> {
+    let mut names = map.read().unwrap().keys().cloned().collect::<Vec<_>>();
+    names.sort();
+    names
+        .into_iter()
+        .map(|value| DynamicSuggestion {
+            value,
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Queries the admin space for declared key-expressions, giving up after [`ADMIN_TIMEOUT`].
+fn query_admin_keys(session: &Session) -> Vec<(String, &'static str)> {
+    let (tx, rx) = mpsc::channel();
+
+    for kind in ADMIN_KINDS {
+        let tx = tx.clone();
+        let _ = session
+            .get(format!("@/*/*/{kind}/**"))
+            .timeout(ADMIN_TIMEOUT)
+            .callback(move |reply| {
+                if let Ok(sample) = reply.result() {
+                    let keyexpr = sample
+                        .key_expr()
+                        .chunks()
+                        .skip(4)
+                        .map(|chunk| chunk.as_str())
+                        .collect::<Vec<_>>();
+                    if !keyexpr.is_empty() {
+                        let _ = tx.send((keyexpr.join("/"), kind));
+                    }
+                }
+            })
+            .wait();
+    }
+    drop(tx);
+
+    let deadline = Instant::now() + ADMIN_TIMEOUT;
+    let mut keys = Vec::new();
+    while let Some(timeout) = deadline.checked_duration_since(Instant::now()) {
+        match rx.recv_timeout(timeout) {
+            Ok(key) => keys.push(key),
+            Err(_) => break,
+        }
+    }
+    keys
+}
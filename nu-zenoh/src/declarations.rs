@@ -0,0 +1,243 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    any::Any,
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, Weak},
+};
+
+use zenoh::Session;
+
+type Entity = Box<dyn Any + Send>;
+type Declare = Box<dyn Fn(&Session) -> zenoh::Result<Entity> + Send + Sync>;
+
+/// A subscriber, queryable or liveliness token that `zenoh session reopen` declares again.
+///
+/// The entity is undeclared once the last reference to its declaration is dropped.
+pub(crate) struct Declaration {
+    kind: &'static str,
+    keyexpr: String,
+    declare: Declare,
+    current: Mutex<Current>,
+}
+
+enum Current {
+    /// The entity and the session it lives on.
+    Declared(Session, Entity),
+    /// Waiting for its session to be reopened.
+    Detached,
+    Undeclared,
+}
+
+impl Declaration {
+    pub(crate) fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    pub(crate) fn keyexpr(&self) -> &str {
+        &self.keyexpr
+    }
+
+    /// Whether the entity was undeclared or the session it lives on was closed.
+    pub(crate) fn is_closed(&self) -> bool {
+        match &*self.current.lock().unwrap() {
+            Current::Declared(session, _) => session.is_closed(),
+            Current::Detached => false,
+            Current::Undeclared => true,
+        }
+    }
+
+    /// Takes the entity out, so that it is no longer declared again.
+    pub(crate) fn take(&self) -> Option<Entity> {
+        match std::mem::replace(&mut *self.current.lock().unwrap(), Current::Undeclared) {
+            Current::Declared(_, entity) => Some(entity),
+            Current::Detached | Current::Undeclared => None,
+        }
+    }
+
+    fn is_undeclared(&self) -> bool {
+        matches!(*self.current.lock().unwrap(), Current::Undeclared)
+    }
+
+    /// Drops the entity, keeping the declaration open until it is declared again.
+    fn detach(&self) {
+        let mut current = self.current.lock().unwrap();
+        if let Current::Declared(..) = *current {
+            *current = Current::Detached;
+        }
+    }
+
+    /// Ends a declaration still waiting for its session.
+    fn abandon(&self) {
+        let mut current = self.current.lock().unwrap();
+        if let Current::Detached = *current {
+            *current = Current::Undeclared;
+        }
+    }
+
+    /// Declares the entity on `session`, dropping the previous one, unless it was undeclared.
+    ///
+    /// A detached declaration that can't be declared again is undeclared.
+    fn redeclare(&self, session: &Session) -> zenoh::Result<bool> {
+        let mut current = self.current.lock().unwrap();
+        if let Current::Undeclared = *current {
+            return Ok(false);
+        }
+
+        match (self.declare)(session) {
+            Ok(entity) => {
+                *current = Current::Declared(session.clone(), entity);
+                Ok(true)
+            }
+            Err(err) => {
+                if let Current::Detached = *current {
+                    *current = Current::Undeclared;
+                }
+                Err(err)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Declaration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Declaration")
+            .field("kind", &self.kind)
+            .field("keyexpr", &self.keyexpr)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Live declarations per session name.
+#[derive(Clone, Default)]
+pub(crate) struct Declarations {
+    live: Arc<Mutex<HashMap<String, Vec<Weak<Declaration>>>>>,
+    /// Declarations kept alive by the shell itself (e.g. tokens restored from a workspace).
+    pinned: Arc<Mutex<HashMap<String, Vec<Arc<Declaration>>>>>,
+}
+
+impl Declarations {
+    /// Declares an entity with `declare` on `session`, tracking it under `session_name`.
+    pub(crate) fn declare<F, E>(
+        &self,
+        session_name: &str,
+        session: &Session,
+        kind: &'static str,
+        keyexpr: String,
+        declare: F,
+    ) -> zenoh::Result<Arc<Declaration>>
+    where
+        F: Fn(&Session) -> zenoh::Result<E> + Send + Sync + 'static,
+        E: Send + 'static,
+    {
+        let declare: Declare =
+            Box::new(move |session| declare(session).map(|entity| Box::new(entity) as Entity));
+        let entity = declare(session)?;
+
+        let declaration = Arc::new(Declaration {
+            kind,
+            keyexpr,
+            declare,
+            current: Mutex::new(Current::Declared(session.clone(), entity)),
+        });
+
+        let mut sessions = self.live.lock().unwrap();
+        let declarations = sessions.entry(session_name.to_string()).or_default();
+        declarations.retain(|declaration| declaration.strong_count() > 0);
+        declarations.push(Arc::downgrade(&declaration));
+
+        Ok(declaration)
+    }
+
+    /// Drops the live entities of the session named `session_name` before it is reopened, see
+    /// [`Declarations::redeclare`]. Their streams keep going until then.
+    pub(crate) fn detach(&self, session_name: &str) {
+        for declaration in self.live_of(session_name) {
+            declaration.detach();
+        }
+    }
+
+    /// Declares the live entities of the session named `session_name` again on `session`.
+    pub(crate) fn redeclare(
+        &self,
+        session_name: &str,
+        session: &Session,
+    ) -> Vec<(Arc<Declaration>, zenoh::Result<()>)> {
+        self.live_of(session_name)
+            .into_iter()
+            .filter_map(|declaration| match declaration.redeclare(session) {
+                Ok(true) => Some((declaration, Ok(()))),
+                Ok(false) => None,
+                Err(err) => Some((declaration, Err(err))),
+            })
+            .collect()
+    }
+
+    /// Keeps a declaration alive until its session is closed.
+    pub(crate) fn pin(&self, session_name: &str, declaration: Arc<Declaration>) {
+        self.pinned
+            .lock()
+            .unwrap()
+            .entry(session_name.to_string())
+            .or_default()
+            .push(declaration);
+    }
+
+    /// The declarations still declared, with the name of their session.
+    pub(crate) fn all(&self) -> Vec<(String, Arc<Declaration>)> {
+        let mut session_names = self
+            .live
+            .lock()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        session_names.sort();
+
+        session_names
+            .into_iter()
+            .flat_map(|session_name| {
+                self.live_of(&session_name)
+                    .into_iter()
+                    .map(move |declaration| (session_name.clone(), declaration))
+            })
+            .collect()
+    }
+
+    fn live_of(&self, session_name: &str) -> Vec<Arc<Declaration>> {
+        self.live
+            .lock()
+            .unwrap()
+            .get(session_name)
+            .map(|declarations| {
+                declarations
+                    .iter()
+                    .filter_map(Weak::upgrade)
+                    .filter(|declaration| !declaration.is_undeclared())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Stops tracking the declarations of the session named `session_name`, ending those that
+    /// were waiting for it to be reopened.
+    pub(crate) fn forget(&self, session_name: &str) {
+        for declaration in self.live_of(session_name) {
+            declaration.abandon();
+        }
+        self.live.lock().unwrap().remove(session_name);
+        self.pinned.lock().unwrap().remove(session_name);
+    }
+}
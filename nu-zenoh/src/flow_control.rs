@@ -0,0 +1,116 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use nu_protocol::{record, IntoValue, Span, Value};
+
+/// What a callback does when the channel to the shell is full.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Overflow {
+    /// Block the callback until the shell catches up.
+    Block,
+    /// Evict the oldest buffered item.
+    DropOldest,
+    /// Discard the incoming item.
+    DropNewest,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FlowControl {
+    pub(crate) buffer: usize,
+    pub(crate) overflow: Overflow,
+}
+
+impl FlowControl {
+    pub(crate) const DEFAULT_BUFFER: usize = 256;
+}
+
+impl Default for FlowControl {
+    fn default() -> Self {
+        Self {
+            buffer: Self::DEFAULT_BUFFER,
+            overflow: Overflow::Block,
+        }
+    }
+}
+
+/// Creates a bounded channel applying the given flow control on the sending side.
+pub(crate) fn channel<T>(flow: FlowControl) -> (Sender<T>, flume::Receiver<T>, Dropped) {
+    let (tx, rx) = flume::bounded(flow.buffer);
+    let dropped = Dropped::default();
+    let sender = Sender {
+        tx,
+        // Holding a receiver in blocking mode would deadlock once the shell stops reading
+        rx: matches!(flow.overflow, Overflow::DropOldest).then(|| rx.clone()),
+        overflow: flow.overflow,
+        dropped: dropped.clone(),
+    };
+    (sender, rx, dropped)
+}
+
+pub(crate) struct Sender<T> {
+    tx: flume::Sender<T>,
+    /// Used to evict the oldest item when dropping from the front.
+    rx: Option<flume::Receiver<T>>,
+    overflow: Overflow,
+    dropped: Dropped,
+}
+
+impl<T> Sender<T> {
+    pub(crate) fn send(&self, item: T) {
+        match self.overflow {
+            Overflow::Block => {
+                let _ = self.tx.send(item);
+            }
+            Overflow::DropNewest => {
+                if let Err(flume::TrySendError::Full(_)) = self.tx.try_send(item) {
+                    self.dropped.increment();
+                }
+            }
+            Overflow::DropOldest => {
+                let mut item = item;
+                while let Err(flume::TrySendError::Full(rejected)) = self.tx.try_send(item) {
+                    if self.rx.as_ref().is_some_and(|rx| rx.try_recv().is_ok()) {
+                        self.dropped.increment();
+                    }
+                    item = rejected;
+                }
+            }
+        }
+    }
+}
+
+/// Number of items discarded because the shell didn't keep up.
+#[derive(Clone, Default)]
+pub(crate) struct Dropped(Arc<AtomicUsize>);
+
+impl Dropped {
+    fn increment(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Yields a single `{dropped: n}` record once polled, if anything was dropped.
+    ///
+    /// This is meant to be chained after a stream so that the count is read when the stream ends.
+    pub(crate) fn report(self, span: Span) -> impl Iterator<Item = Value> {
+        std::iter::once(self).filter_map(move |dropped| {
+            let count = dropped.0.load(Ordering::Relaxed);
+            (count > 0)
+                .then(|| record!("dropped" => (count as i64).into_value(span)).into_value(span))
+        })
+    }
+}
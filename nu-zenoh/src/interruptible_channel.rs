@@ -11,24 +11,86 @@
 // Contributors:
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
-use std::time::Duration;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
-use nu_protocol::Signals;
+use nu_protocol::{LabeledError, Signals, Span, Value};
+use zenoh::Session;
+
+use crate::declarations::Declaration;
+
+/// Conditions that end an [`InterruptibleChannel`] before it is interrupted or disconnected.
+pub(crate) struct StopConditions<T> {
+    /// Maximum number of items.
+    pub(crate) count: Option<usize>,
+    /// Maximum lifetime of the stream.
+    pub(crate) duration: Option<Duration>,
+    /// Maximum time to wait for the next item.
+    pub(crate) idle_timeout: Option<Duration>,
+    /// Predicate ending the stream after the first item it accepts.
+    pub(crate) until: Option<Box<dyn FnMut(&T) -> bool + Send>>,
+}
+
+impl<T> Default for StopConditions<T> {
+    fn default() -> Self {
+        Self {
+            count: None,
+            duration: None,
+            idle_timeout: None,
+            until: None,
+        }
+    }
+}
+
+/// Set once a stream ended because its session was closed (or reopened).
+#[derive(Clone, Default)]
+pub(crate) struct SessionClosed(Arc<AtomicBool>);
+
+impl SessionClosed {
+    pub(crate) fn is_closed(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Yields a single "session closed" error once polled, if the session was closed.
+    ///
+    /// This is meant to be chained after a stream so that it is checked when the stream ends.
+    pub(crate) fn report(self, span: Span) -> impl Iterator<Item = Value> {
+        std::iter::once(self)
+            .filter_map(move |closed| closed.is_closed().then(|| session_closed_error(span)))
+    }
+}
+
+/// Error value ending a stream whose session was closed.
+pub(crate) fn session_closed_error(span: Span) -> Value {
+    Value::error(
+        LabeledError::new("Session closed")
+            .with_label("The session was closed while streaming", span)
+            .into(),
+        span,
+    )
+}
 
-// TODO(fuzzypixelz): interupt on session drop?
 pub(crate) struct InterruptibleChannel<T, D = ()> {
     receiver: flume::Receiver<T>,
     signals: Signals,
-    _data: D,
+    stop: StopConditions<T>,
+    received: usize,
+    deadline: Option<Instant>,
+    last: Instant,
+    /// Checks whether the watched session was closed.
+    session: Option<(Box<dyn Fn() -> bool + Send>, SessionClosed)>,
+    /// Dropped as soon as the stream ends (e.g. to undeclare a subscriber).
+    data: Option<D>,
 }
 
 impl<T> InterruptibleChannel<T> {
     pub(crate) fn new(receiver: flume::Receiver<T>, signals: Signals) -> InterruptibleChannel<T> {
-        InterruptibleChannel {
-            receiver,
-            signals,
-            _data: (),
-        }
+        InterruptibleChannel::with_data(receiver, signals, ())
     }
 }
 
@@ -41,7 +103,62 @@ impl<T, D> InterruptibleChannel<T, D> {
         InterruptibleChannel {
             receiver,
             signals,
-            _data: data,
+            stop: StopConditions::default(),
+            received: 0,
+            deadline: None,
+            last: Instant::now(),
+            session: None,
+            data: Some(data),
+        }
+    }
+
+    pub(crate) fn with_stop_conditions(mut self, stop: StopConditions<T>) -> Self {
+        let now = Instant::now();
+        self.deadline = stop.duration.map(|duration| now + duration);
+        self.last = now;
+        self.stop = stop;
+        self
+    }
+
+    /// Ends the stream when the given session is closed.
+    pub(crate) fn watch_session(self, session: Session) -> (Self, SessionClosed) {
+        self.watch(move || session.is_closed())
+    }
+
+    /// Ends the stream when the session of the declaration is closed, following it across
+    /// 'zenoh session reopen'.
+    pub(crate) fn watch_declaration(self, declaration: Arc<Declaration>) -> (Self, SessionClosed) {
+        self.watch(move || declaration.is_closed())
+    }
+
+    fn watch(mut self, is_closed: impl Fn() -> bool + Send + 'static) -> (Self, SessionClosed) {
+        let closed = SessionClosed::default();
+        self.session = Some((Box::new(is_closed), closed.clone()));
+        (self, closed)
+    }
+
+    /// Checks whether the watched session was closed, recording it if so.
+    fn session_closed(&self) -> bool {
+        self.session.as_ref().is_some_and(|(is_closed, closed)| {
+            let is_closed = is_closed();
+            if is_closed {
+                closed.0.store(true, Ordering::Relaxed);
+            }
+            is_closed
+        })
+    }
+
+    fn finish(&mut self) {
+        self.data = None;
+        self.session = None;
+    }
+
+    /// Earliest instant at which the stream should end if nothing is received.
+    fn stop_at(&self) -> Option<Instant> {
+        let idle = self.stop.idle_timeout.map(|timeout| self.last + timeout);
+        match (self.deadline, idle) {
+            (Some(deadline), Some(idle)) => Some(deadline.min(idle)),
+            (deadline, idle) => deadline.or(idle),
         }
     }
 }
@@ -55,14 +172,47 @@ impl<T, D> Iterator for InterruptibleChannel<T, D> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_none() || self.stop.count.is_some_and(|count| self.received >= count) {
+            self.finish();
+            return None;
+        }
+
         loop {
-            if self.signals.interrupted() {
+            let now = Instant::now();
+            let stop_at = self.stop_at();
+
+            if self.signals.interrupted() || stop_at.is_some_and(|stop_at| now >= stop_at) {
+                self.finish();
                 return None;
-            } else {
-                match self.receiver.recv_timeout(Self::TIMEOUT) {
-                    Ok(item) => return Some(item),
-                    Err(flume::RecvTimeoutError::Timeout) => continue,
-                    Err(flume::RecvTimeoutError::Disconnected) => return None,
+            }
+
+            let timeout = now + Self::TIMEOUT;
+            match self
+                .receiver
+                .recv_deadline(stop_at.map_or(timeout, |stop_at| stop_at.min(timeout)))
+            {
+                Ok(item) => {
+                    self.received += 1;
+                    self.last = Instant::now();
+
+                    let done = self.stop.count.is_some_and(|count| self.received >= count)
+                        || self.stop.until.as_mut().is_some_and(|until| until(&item));
+                    if done {
+                        self.finish();
+                    }
+
+                    return Some(item);
+                }
+                Err(flume::RecvTimeoutError::Timeout) => {
+                    if self.session_closed() {
+                        self.finish();
+                        return None;
+                    }
+                }
+                Err(flume::RecvTimeoutError::Disconnected) => {
+                    self.session_closed();
+                    self.finish();
+                    return None;
                 }
             }
         }
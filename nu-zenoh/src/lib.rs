@@ -13,19 +13,32 @@
 //
 use std::{
     collections::HashMap,
-    sync::{Arc, RwLock},
+    sync::{Arc, Mutex, RwLock},
 };
 
 use nu_protocol::{
     engine::{EngineState, StateWorkingSet},
     LabeledError,
 };
+use tempfile::TempDir;
 use zenoh::{internal::runtime::Runtime, Session, Wait};
 
+use crate::{
+    completion::{AdminKeys, RecentKeys},
+    declarations::Declarations,
+    logging::{LogBuffer, Logging},
+    session_meta::{SessionMeta, SessionSource},
+};
+
 mod call_ext2;
 mod cmd;
+mod completion;
 mod conv;
+mod declarations;
+mod flow_control;
 mod interruptible_channel;
+mod logging;
+mod session_meta;
 mod signature_ext;
 
 #[derive(Debug, Clone)]
@@ -50,12 +63,14 @@ pub fn add_zenoh_context(mut engine_state: EngineState, options: Config) -> Engi
             working_set.add_decl(Box::new(cmd::runtime::list::List::new(state.clone())));
             working_set.add_decl(Box::new(cmd::runtime::open::Open::new(state.clone())));
             working_set.add_decl(Box::new(cmd::runtime::close::Close::new(state.clone())));
+            working_set.add_decl(Box::new(cmd::runtime::info::Info::new(state.clone())));
         }
 
         working_set.add_decl(Box::new(cmd::put::Put::new(state.clone())));
         working_set.add_decl(Box::new(cmd::delete::Delete::new(state.clone())));
         working_set.add_decl(Box::new(cmd::get::Get::new(state.clone())));
         working_set.add_decl(Box::new(cmd::sub::Sub::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::watch::Watch::new(state.clone())));
         working_set.add_decl(Box::new(cmd::zid::Zid::new(state.clone())));
 
         working_set.add_decl(Box::new(cmd::liveliness::decl::Decl::new(state.clone())));
@@ -67,15 +82,44 @@ pub fn add_zenoh_context(mut engine_state: EngineState, options: Config) -> Engi
         working_set.add_decl(Box::new(cmd::session::list::List::new(state.clone())));
         working_set.add_decl(Box::new(cmd::session::open::Open::new(state.clone())));
         working_set.add_decl(Box::new(cmd::session::close::Close::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::session::events::Events::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::session::reopen::Reopen::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::session::select::Use::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::with_session::WithSession::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::workspace::save::Save::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::workspace::load::Load::new(state.clone())));
+
+        working_set.add_decl(Box::new(cmd::sandbox::up::Up::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::sandbox::down::Down::new(state.clone())));
 
         working_set.add_decl(Box::new(cmd::log_path::LogPath::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::log::Log::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::log::level::Level::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::log::rotate::Rotate::new(state.clone())));
         working_set.add_decl(Box::new(cmd::reply::Reply::new(state.clone())));
         working_set.add_decl(Box::new(cmd::scout::Scout::new(state.clone())));
         working_set.add_decl(Box::new(cmd::info::Info::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::ping::Ping::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::pong::Pong::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::bench::publish::Pub::new(state.clone())));
+        working_set.add_decl(Box::new(cmd::bench::subscribe::Sub::new(state.clone())));
         working_set.add_decl(Box::new(cmd::config::Config::new(state)));
+        working_set.add_decl(Box::new(cmd::config::new::New));
+        working_set.add_decl(Box::new(cmd::config::validate::Validate));
 
         working_set.add_decl(Box::new(cmd::keyexpr::Includes));
         working_set.add_decl(Box::new(cmd::keyexpr::Intersects));
+        working_set.add_decl(Box::new(cmd::keyexpr::canonize::Canonize));
+        working_set.add_decl(Box::new(cmd::keyexpr::validate::Validate));
+        working_set.add_decl(Box::new(cmd::keyexpr::join::Join));
+        working_set.add_decl(Box::new(cmd::keyexpr::concat::Concat));
+        working_set.add_decl(Box::new(cmd::keyexpr::relation::Relation));
+        working_set.add_decl(Box::new(cmd::keyexpr::chunks::Chunks));
+        working_set.add_decl(Box::new(cmd::keyexpr::cover::Cover));
+        working_set.add_decl(Box::new(cmd::keyexpr::overlap::Overlap));
+        working_set.add_decl(Box::new(cmd::keyexpr::matches::Match));
+        working_set.add_decl(Box::new(cmd::keyexpr::format::parse::Parse));
+        working_set.add_decl(Box::new(cmd::keyexpr::format::build::Build));
 
         working_set.render()
     };
@@ -91,38 +135,100 @@ pub fn add_zenoh_context(mut engine_state: EngineState, options: Config) -> Engi
 struct State {
     options: Config,
     sessions: Arc<RwLock<HashMap<String, Session>>>,
+    session_meta: Arc<RwLock<HashMap<String, SessionMeta>>>,
     runtimes: Arc<RwLock<HashMap<String, Runtime>>>,
+    /// Unix socket directories of the sandboxes, removed when dropped.
+    sandbox_dirs: Arc<Mutex<HashMap<String, TempDir>>>,
+    logs: LogBuffer,
+    logging: Logging,
+    recent_keys: RecentKeys,
+    admin_keys: AdminKeys,
+    declarations: Declarations,
 }
 
 impl State {
     const DEFAULT_SESSION_NAME: &str = "default";
+    /// Environment variable naming the session used when `--session` is not given.
+    const SESSION_ENV_VAR: &str = "ZENOH_SESSION";
 
     fn new(options: Config) -> Self {
+        let logs = LogBuffer::default();
+        let logging = logging::init(&logs);
+
         let mut sessions = HashMap::new();
+        let mut session_meta = HashMap::new();
         if !options.no_default_session {
             let default_session = zenoh::open(zenoh::Config::default())
                 .wait()
                 .expect("could not open default session");
             sessions.insert(Self::DEFAULT_SESSION_NAME.to_string(), default_session);
+            session_meta.insert(
+                Self::DEFAULT_SESSION_NAME.to_string(),
+                SessionMeta::now(SessionSource::Default),
+            );
         }
 
         Self {
             options,
             sessions: Arc::new(RwLock::new(sessions)),
+            session_meta: Arc::new(RwLock::new(session_meta)),
             runtimes: Arc::new(RwLock::new(HashMap::new())),
+            sandbox_dirs: Arc::new(Mutex::new(HashMap::new())),
+            logs,
+            logging,
+            recent_keys: RecentKeys::default(),
+            admin_keys: AdminKeys::default(),
+            declarations: Declarations::default(),
         }
     }
 }
 
 impl State {
+    /// Records how the session named `name` was (re)opened.
+    pub(crate) fn session_opened(&self, name: &str, source: SessionSource) {
+        self.session_meta
+            .write()
+            .unwrap()
+            .insert(name.to_string(), SessionMeta::now(source));
+    }
+
+    /// Drops what is known about the session named `name` once it is closed.
+    pub(crate) fn session_closed(&self, name: &str) {
+        self.session_meta.write().unwrap().remove(name);
+        self.recent_keys.forget(name);
+        self.admin_keys.forget(name);
+        self.declarations.forget(name);
+    }
+
+    /// The names of the sessions opened on the runtime named `runtime_name`, sorted.
+    pub(crate) fn attached_sessions(&self, runtime_name: &str) -> Vec<String> {
+        let mut names = self
+            .session_meta
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, meta)| {
+                matches!(&meta.source, SessionSource::Runtime(runtime) if runtime == runtime_name)
+            })
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
     pub(crate) fn with_session<F, T>(&self, name: &str, f: F) -> Result<T, LabeledError>
     where
         F: FnOnce(&Session) -> T,
     {
         let sessions = self.sessions.read().unwrap();
-        let session = sessions
-            .get(name)
-            .ok_or_else(|| LabeledError::new(format!("session '{name}' not found")))?;
+        let session = sessions.get(name).ok_or_else(|| {
+            let mut names = sessions.keys().map(String::as_str).collect::<Vec<_>>();
+            names.sort();
+            LabeledError::new(format!(
+                "session '{name}' not found (open sessions: {})",
+                names.join(", ")
+            ))
+        })?;
         Ok(f(session))
     }
 }
@@ -0,0 +1,337 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::{
+    collections::VecDeque,
+    fmt,
+    fs::File,
+    io::{self, Write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, FixedOffset, Local};
+use nu_protocol::{record, IntoValue, Record, Span, Value};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record as SpanRecord},
+    Event, Level, Subscriber,
+};
+use tracing_subscriber::{
+    filter::ParseError,
+    fmt::{format::FmtSpan, MakeWriter},
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    reload,
+    util::SubscriberInitExt,
+    EnvFilter, Layer, Registry,
+};
+
+/// Installs the global tracing subscriber, which writes to a temporary log file and to `buffer`.
+///
+/// This should be called before opening any session so that its spans are captured.
+pub(crate) fn init(buffer: &LogBuffer) -> Logging {
+    let log_dir = tempfile::tempdir().unwrap().keep();
+    let log_path = log_dir.join("zenoh.log");
+
+    const ENV_FILTER_NAME: &str = "ZENOH_NU_LOG";
+    const ENV_FILTER_DEFAULT: &str = "zenoh=info";
+
+    // Verbose levels are opt-in, through the environment or `zenoh log level`
+    let env_filter = EnvFilter::try_from_env(ENV_FILTER_NAME)
+        .or_else(|_| EnvFilter::try_from_default_env())
+        .unwrap_or_else(|_| EnvFilter::new(ENV_FILTER_DEFAULT));
+    let (env_filter, filter) = reload::Layer::new(env_filter);
+
+    let file = LogFile(Arc::new(Mutex::new(File::create(&log_path).unwrap())));
+
+    let fmt = tracing_subscriber::fmt::layer()
+        .with_writer(file.clone())
+        .with_ansi(false)
+        .with_span_events(FmtSpan::ACTIVE);
+
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(fmt)
+        .with(buffer.layer())
+        .init();
+
+    Logging {
+        filter,
+        file,
+        log_dir,
+        log_path: Arc::new(Mutex::new((log_path, 0))),
+    }
+}
+
+/// Handle to the global tracing subscriber installed by [`init`].
+#[derive(Clone)]
+pub(crate) struct Logging {
+    filter: reload::Handle<EnvFilter, Registry>,
+    file: LogFile,
+    log_dir: PathBuf,
+    /// Current log file path and number of rotations so far.
+    log_path: Arc<Mutex<(PathBuf, usize)>>,
+}
+
+impl Logging {
+    /// Returns the path of the current log file.
+    pub(crate) fn path(&self) -> PathBuf {
+        self.log_path.lock().unwrap().0.clone()
+    }
+
+    /// Returns the current filter directives.
+    pub(crate) fn filter(&self) -> String {
+        self.filter
+            .with_current(|filter| filter.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Replaces the filter with the given directives (e.g. `zenoh=debug`).
+    pub(crate) fn set_filter(&self, directives: &str) -> Result<(), ParseError> {
+        let filter = EnvFilter::try_new(directives)?;
+        // NOTE: this can only fail if the subscriber was dropped, which never happens
+        let _ = self.filter.reload(filter);
+        Ok(())
+    }
+
+    /// Starts writing to a fresh log file and returns the path of the previous one.
+    pub(crate) fn rotate(&self) -> io::Result<PathBuf> {
+        let mut log_path = self.log_path.lock().unwrap();
+        let (old_path, rotations) = &mut *log_path;
+
+        let new_path = self.log_dir.join(format!("zenoh.{}.log", *rotations + 1));
+        let new_file = File::create(&new_path)?;
+
+        let mut file = self.file.0.lock().unwrap();
+        file.flush()?;
+        *file = new_file;
+
+        *rotations += 1;
+        Ok(std::mem::replace(old_path, new_path))
+    }
+}
+
+/// Log file writer which can be swapped on rotation.
+#[derive(Clone)]
+struct LogFile(Arc<Mutex<File>>);
+
+impl Write for LogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.lock().unwrap().flush()
+    }
+}
+
+impl<'a> MakeWriter<'a> for LogFile {
+    type Writer = LogFile;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// A tracing event captured by [`LogLayer`].
+#[derive(Debug, Clone)]
+pub(crate) struct LogRecord {
+    pub(crate) time: DateTime<FixedOffset>,
+    pub(crate) level: Level,
+    pub(crate) target: String,
+    /// (Possibly shortened) ZID of the session or runtime which emitted the event, if known.
+    pub(crate) zid: Option<String>,
+    pub(crate) fields: Vec<(String, String)>,
+    pub(crate) message: String,
+}
+
+impl LogRecord {
+    /// Returns true if this record was emitted by the session or runtime with the given ZID.
+    ///
+    /// Zenoh tags its spans with shortened ZIDs (i.e. the 4 most significant bytes in hex).
+    pub(crate) fn matches_zid(&self, zid: &str) -> bool {
+        let Some(record_zid) = self.zid.as_deref() else {
+            return false;
+        };
+
+        let padded = if zid.len() % 2 == 1 {
+            format!("0{zid}")
+        } else {
+            zid.to_string()
+        };
+        let short = match padded[..padded.len().min(8)].trim_start_matches('0') {
+            "" => "0",
+            short => short,
+        };
+
+        record_zid == zid || record_zid == short
+    }
+
+    pub(crate) fn into_value(self, span: Span) -> Value {
+        record!(
+            "time" => Value::date(self.time, span),
+            "level" => self.level.to_string().into_value(span),
+            "target" => self.target.into_value(span),
+            "zid" => self.zid.map(|zid| zid.into_value(span)).unwrap_or_default(),
+            "fields" => Record::from_iter(
+                self.fields.into_iter().map(|(k, v)| (k, v.into_value(span))),
+            ).into_value(span),
+            "message" => self.message.into_value(span),
+        )
+        .into_value(span)
+    }
+}
+
+/// In-memory ring of the most recent log records, shared by all `zenoh log` commands.
+#[derive(Clone, Default)]
+pub(crate) struct LogBuffer {
+    inner: Arc<Mutex<LogBufferInner>>,
+}
+
+#[derive(Default)]
+struct LogBufferInner {
+    records: VecDeque<LogRecord>,
+    followers: Vec<flume::Sender<LogRecord>>,
+}
+
+impl LogBuffer {
+    /// Maximum number of records kept in memory.
+    const CAPACITY: usize = 8192;
+
+    /// Maximum number of records queued for a follower before new ones are dropped.
+    const FOLLOWER_CHANNEL_SIZE: usize = 1024;
+
+    pub(crate) fn layer(&self) -> LogLayer {
+        LogLayer {
+            buffer: self.clone(),
+        }
+    }
+
+    /// Returns the buffered records along with a receiver for all subsequent ones.
+    pub(crate) fn follow(&self) -> (Vec<LogRecord>, flume::Receiver<LogRecord>) {
+        let (tx, rx) = flume::bounded(Self::FOLLOWER_CHANNEL_SIZE);
+        let mut inner = self.inner.lock().unwrap();
+        inner.followers.push(tx);
+        (inner.records.iter().cloned().collect(), rx)
+    }
+
+    /// Returns the buffered records.
+    pub(crate) fn records(&self) -> Vec<LogRecord> {
+        self.inner.lock().unwrap().records.iter().cloned().collect()
+    }
+
+    fn push(&self, record: LogRecord) {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.followers.retain(|tx| {
+            !matches!(
+                tx.try_send(record.clone()),
+                Err(flume::TrySendError::Disconnected(_))
+            )
+        });
+
+        if inner.records.len() == Self::CAPACITY {
+            inner.records.pop_front();
+        }
+        inner.records.push_back(record);
+    }
+}
+
+/// Tracing layer which attributes events to sessions and stores them in a [`LogBuffer`].
+///
+/// An event is attributed to the innermost enclosing span with a `zid` field (Zenoh declares
+/// such spans for runtimes and sessions), or to its own `zid` field if it has one.
+pub(crate) struct LogLayer {
+    buffer: LogBuffer,
+}
+
+/// ZID attached to a span.
+struct SpanZid(String);
+
+#[derive(Default)]
+struct FieldVisitor {
+    fields: Vec<(String, String)>,
+    message: Option<String>,
+}
+
+impl FieldVisitor {
+    fn zid(&self) -> Option<&str> {
+        self.fields
+            .iter()
+            .find_map(|(k, v)| (k == "zid").then_some(v.as_str()))
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_string());
+        } else {
+            self.fields
+                .push((field.name().to_string(), value.to_string()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{value:?}"));
+        } else {
+            self.fields
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+}
+
+impl<S> Layer<S> for LogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+
+        if let (Some(zid), Some(span)) = (visitor.zid(), ctx.span(id)) {
+            span.extensions_mut().insert(SpanZid(zid.to_string()));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &SpanRecord<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        values.record(&mut visitor);
+
+        if let (Some(zid), Some(span)) = (visitor.zid(), ctx.span(id)) {
+            span.extensions_mut().replace(SpanZid(zid.to_string()));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+
+        let zid = visitor.zid().map(str::to_string).or_else(|| {
+            ctx.event_scope(event)?
+                .find_map(|span| span.extensions().get::<SpanZid>().map(|zid| zid.0.clone()))
+        });
+
+        let metadata = event.metadata();
+        self.buffer.push(LogRecord {
+            time: Local::now().fixed_offset(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            zid,
+            fields: visitor.fields,
+            message: visitor.message.unwrap_or_default(),
+        });
+    }
+}
@@ -0,0 +1,56 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+use std::path::PathBuf;
+
+use chrono::{DateTime, FixedOffset, Local};
+
+/// How a session was opened.
+#[derive(Debug, Clone)]
+pub(crate) enum SessionSource {
+    /// The session opened at startup, or with no configuration.
+    Default,
+    ConfigFile(PathBuf),
+    Record,
+    /// A client connecting to scouted nodes (see `zenoh session open --connect-from-scout`).
+    Scout,
+    Runtime(String),
+}
+
+impl SessionSource {
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            SessionSource::Default => "default",
+            SessionSource::ConfigFile(_) => "config-file",
+            SessionSource::Record => "record",
+            SessionSource::Scout => "scout",
+            SessionSource::Runtime(_) => "runtime",
+        }
+    }
+}
+
+/// What is known about a session beyond the session itself, reported by `zenoh session list`.
+#[derive(Debug, Clone)]
+pub(crate) struct SessionMeta {
+    pub(crate) opened: DateTime<FixedOffset>,
+    pub(crate) source: SessionSource,
+}
+
+impl SessionMeta {
+    pub(crate) fn now(source: SessionSource) -> Self {
+        Self {
+            opened: Local::now().fixed_offset(),
+            source,
+        }
+    }
+}
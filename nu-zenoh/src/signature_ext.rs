@@ -20,6 +20,10 @@ pub(crate) trait SignatureExt: Sized {
 
     fn publication(self) -> Self;
 
+    fn qos(self) -> Self;
+
+    fn flow_control(self) -> Self;
+
     fn allowed_destination(self) -> Self;
 
     fn allowed_origin(self) -> Self;
@@ -29,6 +33,8 @@ pub(crate) trait SignatureExt: Sized {
     fn complete(self) -> Self;
 
     fn keyexpr(self) -> Self;
+
+    fn size(self) -> Self;
 }
 
 impl SignatureExt for Signature {
@@ -36,7 +42,7 @@ impl SignatureExt for Signature {
         self.named(
             "session",
             SyntaxShape::String,
-            "Session name (defauls to 'default')",
+            "Session name (defaults to $env.ZENOH_SESSION, or 'default')",
             Some('s'),
         )
     }
@@ -66,7 +72,34 @@ impl SignatureExt for Signature {
 
     fn publication(self) -> Self {
         self.keyexpr()
-            .named("priority", SyntaxShape::String, "Priority (0-7)", None)
+            .qos()
+            .named("attachment", SyntaxShape::String, "Attachment data", None)
+            .named(
+                "timestamp",
+                SyntaxShape::String,
+                "Custom timestamp (expects the '<ZID>/<RFC3339>' format)",
+                None,
+            )
+            .allowed_destination()
+    }
+
+    fn flow_control(self) -> Self {
+        self.named(
+            "buffer",
+            SyntaxShape::Int,
+            "Number of items buffered between Zenoh and the shell (defaults to 256)",
+            None,
+        )
+        .named(
+            "overflow",
+            SyntaxShape::String,
+            "Behavior when the buffer is full (either 'block', 'drop-oldest' or 'drop-newest', defaults to 'block')",
+            None,
+        )
+    }
+
+    fn qos(self) -> Self {
+        self.named("priority", SyntaxShape::String, "Priority (0-7)", None)
             .named(
                 "congestion-control",
                 SyntaxShape::Int,
@@ -85,14 +118,6 @@ impl SignatureExt for Signature {
                 "Sets express transmission",
                 None,
             )
-            .named("attachment", SyntaxShape::String, "Attachment data", None)
-            .named(
-                "timestamp",
-                SyntaxShape::String,
-                "Custom timestamp (expects the '<ZID>/<RFC3339>' format)",
-                None,
-            )
-            .allowed_destination()
     }
 
     fn encoding(self) -> Self {
@@ -116,4 +141,13 @@ impl SignatureExt for Signature {
     fn keyexpr(self) -> Self {
         self.required("keyexpr", SyntaxShape::String, "Key expression")
     }
+
+    fn size(self) -> Self {
+        self.named(
+            "size",
+            SyntaxShape::OneOf(vec![SyntaxShape::Int, SyntaxShape::Filesize]),
+            "Payload size (e.g. 64 or 1KiB)",
+            None,
+        )
+    }
 }
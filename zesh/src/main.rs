@@ -89,6 +89,8 @@ fn main() {
         );
     }
 
+    // The REPL completer of nu-cli asks commands for dynamic completions, which is how
+    // `zenoh sub` and `zenoh get` complete key-expressions: no custom completer is needed.
     if let Err(err) = nu_cli::evaluate_repl(&mut engine_state, stack, None, None, entire_start_time)
     {
         eprintln!("Error starting REPL: {err}");